@@ -0,0 +1,187 @@
+use crate::DoiError;
+#[cfg(feature = "metadata")]
+use crate::JsonValue;
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Cache of resolution and metadata results shared between [`Doi`](crate::Doi) instances.
+///
+/// All [`Doi`](crate::Doi) instances built by the same [`DoiBuilder`](crate::DoiBuilder)
+/// (with [`DoiBuilder::cache`](crate::DoiBuilder::cache) enabled) and their clones share one cache.
+/// Entries are keyed by the lowercase DOI number.
+///
+/// Concurrent requests for the same DOI are coalesced (single-flight):
+/// only the first caller sends the request, and the others wait for its result.
+/// Successful results are kept in the cache,
+/// while errors are only shared with the callers that were waiting.
+/// The exception is [`DoiError::NotFound`] errors, which are kept for the negative TTL if set
+/// (see [`Self::set_negative_ttl`]), so that missing DOIs are not queried again.
+#[derive(Debug, Default)]
+pub struct DoiCache {
+    pub(crate) resolved: SingleFlight<String>,
+    #[cfg(feature = "metadata")]
+    pub(crate) metadata_json: SingleFlight<JsonValue>,
+    negative_ttl: Mutex<Option<Duration>>,
+}
+
+impl DoiCache {
+    /// Creates a new empty [`DoiCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long "not found" (404) errors are cached (disabled if `None`, the default).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiCache;
+    /// use std::time::Duration;
+    /// let cache = DoiCache::new();
+    /// cache.set_negative_ttl(Some(Duration::from_secs(600)));
+    /// assert_eq!(cache.negative_ttl(), Some(Duration::from_secs(600)));
+    /// ```
+    pub fn set_negative_ttl(&self, ttl: Option<Duration>) {
+        *self.negative_ttl.lock().unwrap() = ttl;
+    }
+
+    /// Returns how long "not found" (404) errors are cached (disabled if `None`).
+    pub fn negative_ttl(&self) -> Option<Duration> {
+        *self.negative_ttl.lock().unwrap()
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        self.resolved.clear();
+        #[cfg(feature = "metadata")]
+        self.metadata_json.clear();
+    }
+}
+
+/// Result of a request.
+type SlotResult<T> = Result<T, DoiError>;
+
+/// Result slot of a (possibly in-flight) request.
+#[derive(Debug)]
+struct Slot<T> {
+    /// `None` while the request is in flight, otherwise the result and its expiry (if any).
+    result: Mutex<Option<(SlotResult<T>, Option<Instant>)>>,
+    ready: Condvar,
+}
+
+impl<T> Slot<T> {
+    /// Sets the result and wakes up all waiters.
+    fn fill(&self, result: SlotResult<T>, expiry: Option<Instant>) {
+        *self.result.lock().unwrap() = Some((result, expiry));
+        self.ready.notify_all();
+    }
+
+    /// Checks if the result is set and has expired.
+    fn is_expired(&self) -> bool {
+        matches!(&*self.result.lock().unwrap(), Some((_, Some(expiry))) if *expiry <= Instant::now())
+    }
+}
+
+/// Guard of an in-flight request, dropped only if the request panics.
+///
+/// The slot is removed (so that the next call retries) and filled with an error,
+/// since the waiters would otherwise wait forever.
+struct PanicGuard<'a, T> {
+    flight: &'a SingleFlight<T>,
+    key: &'a str,
+    slot: &'a Arc<Slot<T>>,
+}
+
+impl<T> Drop for PanicGuard<'_, T> {
+    fn drop(&mut self) {
+        self.flight.remove(self.key, self.slot);
+        let error = io::Error::other("The coalesced request panicked");
+        self.slot.fill(Err(error.into()), None);
+    }
+}
+
+/// Map of result slots coalescing concurrent requests for the same key.
+#[derive(Debug)]
+pub(crate) struct SingleFlight<T> {
+    slots: Mutex<HashMap<String, Arc<Slot<T>>>>,
+}
+
+impl<T> Default for SingleFlight<T> {
+    fn default() -> Self {
+        Self {
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> SingleFlight<T> {
+    /// Removes the slot of `key` if it is still `slot`.
+    fn remove(&self, key: &str, slot: &Arc<Slot<T>>) {
+        let mut slots = self.slots.lock().unwrap();
+        if slots.get(key).is_some_and(|s| Arc::ptr_eq(s, slot)) {
+            slots.remove(key);
+        }
+    }
+}
+
+impl<T: Clone> SingleFlight<T> {
+    /// Returns the cached result for `key`, waits for the in-flight request for `key`,
+    /// or calls `fetch` if there is neither.
+    ///
+    /// A "not found" error is cached for `negative_ttl` if set.
+    pub(crate) fn get_or_fetch<F>(
+        &self,
+        key: &str,
+        negative_ttl: Option<Duration>,
+        fetch: F,
+    ) -> Result<T, DoiError>
+    where
+        F: FnOnce() -> Result<T, DoiError>,
+    {
+        let mut slots = self.slots.lock().unwrap();
+        if let Some(slot) = slots.get(key).cloned().filter(|slot| !slot.is_expired()) {
+            drop(slots);
+            let mut result = slot.result.lock().unwrap();
+            while result.is_none() {
+                result = slot.ready.wait(result).unwrap();
+            }
+            return result.as_ref().unwrap().0.clone();
+        }
+        let slot = Arc::new(Slot {
+            result: Mutex::new(None),
+            ready: Condvar::new(),
+        });
+        slots.insert(key.to_string(), slot.clone());
+        drop(slots);
+
+        // Releases the waiters with an error if `fetch` panics.
+        let guard = PanicGuard {
+            flight: self,
+            key,
+            slot: &slot,
+        };
+        let result = fetch();
+        std::mem::forget(guard);
+        let (slot_result, expiry) = match &result {
+            Ok(value) => (Ok(value.clone()), None),
+            Err(e) => {
+                let expiry = negative_ttl
+                    .filter(|_| matches!(e, DoiError::NotFound))
+                    .map(|ttl| Instant::now() + ttl);
+                if expiry.is_none() {
+                    self.remove(key, &slot);
+                }
+                (Err(e.clone()), expiry)
+            }
+        };
+        slot.fill(slot_result, expiry);
+        result
+    }
+
+    /// Removes all entries.
+    fn clear(&self) {
+        self.slots.lock().unwrap().clear();
+    }
+}
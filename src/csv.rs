@@ -0,0 +1,63 @@
+use crate::{Doi, DoiError};
+use std::io::Write;
+
+/// Quotes a CSV field if it contains a comma, a quote, or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Resolves the DOIs, fetches their titles, and writes the results as CSV.
+///
+/// The output has a header row `doi,resolved_url,title,error` and one row per DOI.
+/// A failed resolution or metadata request does not stop the export:
+/// the corresponding columns are left empty and the error message is written
+/// to the `error` column (the resolution error if both fail).
+/// Fields are quoted as in [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180) when needed.
+/// This requires the `csv` feature.
+///
+/// # Arguments
+///
+/// * `dois` - A slice of [`Doi`] to export.
+/// * `out` - A writer receiving the CSV output.
+///
+/// # Errors
+///
+/// Returns [`DoiError::Io`] if writing to `out` fails.
+///
+/// # Example
+///
+/// ```
+/// use doi::{write_csv, Doi};
+/// let dois = [Doi::new("10.1109/TCSII.2024.3366282")];
+/// let mut csv = Vec::new();
+/// write_csv(&dois, &mut csv).unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// assert!(csv.starts_with("doi,resolved_url,title,error\n10.1109/TCSII.2024.3366282,"));
+/// ```
+pub fn write_csv<W: Write>(dois: &[Doi], mut out: W) -> Result<(), DoiError> {
+    writeln!(out, "doi,resolved_url,title,error")?;
+    for doi in dois {
+        let resolved = doi.resolve();
+        let metadata = doi.metadata();
+        let error = match (&resolved, &metadata) {
+            (Err(e), _) | (_, Err(e)) => e.to_string(),
+            _ => String::new(),
+        };
+        let resolved = resolved.unwrap_or_default();
+        let title = metadata.ok().and_then(|m| m.title).unwrap_or_default();
+        let fields = [
+            doi.doi.as_deref().unwrap_or_default(),
+            &resolved,
+            &title,
+            &error,
+        ];
+        let row = fields.map(csv_field).join(",");
+        writeln!(out, "{}", row)?;
+    }
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,283 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// Error returned by the fallible methods of this crate.
+///
+/// The underlying `ureq` and I/O errors are reference-counted,
+/// so that the error can be cloned (e.g., shared among the callers waiting for a cached request).
+///
+/// # Example
+///
+/// ```
+/// use doi::{Doi, DoiError};
+/// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+/// match doi.resolve() {
+///     Ok(link) => println!("Resolved link: {}", link),
+///     Err(DoiError::NotFound) => eprintln!("DOI not found"),
+///     Err(e) => eprintln!("Error: {}", e),
+/// }
+/// assert!(matches!(Doi::default().resolve(), Err(DoiError::NotSet)));
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DoiError {
+    /// The DOI is not set, i.e., `None`.
+    NotSet,
+    /// The resolver does not know the DOI (it responded with `404 Not Found`).
+    NotFound,
+    /// An HTTP request failed, either in transport or with an error status code.
+    Http(Arc<ureq::Error>),
+    /// A request timed out (see [`DoiBuilder::timeout`](crate::DoiBuilder::timeout)),
+    /// or the deadline was exceeded (see [`DoiBuilder::deadline`](crate::DoiBuilder::deadline)).
+    ///
+    /// The underlying `ureq::Error` or `std::io::Error` is kept as the source.
+    Timeout(Arc<dyn Error + Send + Sync>),
+    /// Reading a response failed.
+    Io(Arc<io::Error>),
+    /// The DOI or a response (e.g., the JSON metadata) could not be parsed.
+    Parse(String),
+    /// The redirects could not be followed (e.g., too many redirects).
+    Redirect(String),
+    /// The configuration is invalid (e.g., a malformed proxy URL).
+    Config(String),
+}
+
+impl DoiError {
+    /// Converts the error of a request to `resolver_url` (the DOI URL).
+    ///
+    /// A `404 Not Found` response from the resolver itself becomes [`Self::NotFound`],
+    /// while one from a redirect target (e.g., a dead landing page) is kept as is.
+    pub(crate) fn from_call(error: ureq::Error, resolver_url: &str) -> Self {
+        match &error {
+            ureq::Error::Status(404, response) if response.get_url() == resolver_url => {
+                Self::NotFound
+            }
+            _ => error.into(),
+        }
+    }
+
+    /// Checks if the error is a transient failure worth retrying
+    /// (see [`DoiBuilder::retries`](crate::DoiBuilder::retries)).
+    pub(crate) fn is_transient(&self) -> bool {
+        matches!(
+            self.kind(),
+            Some(
+                ResolveErrorKind::Timeout
+                    | ResolveErrorKind::Connection
+                    | ResolveErrorKind::Status(429 | 500 | 502 | 503 | 504)
+            )
+        )
+    }
+
+    /// Returns the kind of the failed HTTP request (see [`ResolveErrorKind::of`]).
+    ///
+    /// Returns `None` if the error is not caused by an HTTP request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{Doi, ResolveErrorKind};
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// if let Err(e) = doi.resolve() {
+    ///     match e.kind() {
+    ///         Some(ResolveErrorKind::Timeout) => eprintln!("Timed out, try again later"),
+    ///         kind => eprintln!("Error ({:?}): {}", kind, e),
+    ///     }
+    /// }
+    /// assert_eq!(Doi::default().resolve().unwrap_err().kind(), None);
+    /// ```
+    pub fn kind(&self) -> Option<ResolveErrorKind> {
+        match self {
+            Self::NotFound => Some(ResolveErrorKind::Status(404)),
+            Self::Timeout(_) => Some(ResolveErrorKind::Timeout),
+            Self::Http(e) => Some(ResolveErrorKind::from(&**e)),
+            Self::Io(e) => Some(ResolveErrorKind::from_io(e)),
+            Self::Redirect(_) => Some(ResolveErrorKind::Other),
+            Self::NotSet | Self::Parse(_) | Self::Config(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for DoiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSet => f.write_str("DOI is not set"),
+            Self::NotFound => f.write_str("DOI not found (404 Not Found)"),
+            Self::Timeout(e) => write!(f, "Request timed out: {}", e),
+            Self::Http(e) => e.fmt(f),
+            Self::Io(e) => e.fmt(f),
+            Self::Parse(message) | Self::Redirect(message) | Self::Config(message) => {
+                f.write_str(message)
+            }
+        }
+    }
+}
+
+impl Error for DoiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Timeout(e) => Some(&**e),
+            Self::Http(e) => Some(&**e),
+            Self::Io(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ureq::Error> for DoiError {
+    fn from(error: ureq::Error) -> Self {
+        match ResolveErrorKind::from(&error) {
+            ResolveErrorKind::Timeout => Self::Timeout(Arc::new(error)),
+            _ => Self::Http(Arc::new(error)),
+        }
+    }
+}
+
+impl From<io::Error> for DoiError {
+    fn from(error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::TimedOut => Self::Timeout(Arc::new(error)),
+            _ => Self::Io(Arc::new(error)),
+        }
+    }
+}
+
+impl From<ParseDoiError> for DoiError {
+    fn from(error: ParseDoiError) -> Self {
+        Self::Parse(error.to_string())
+    }
+}
+
+/// Error parsing a malformed DOI (see [`Doi::from_str`](crate::Doi#method.from_str)
+/// and [`Doi::from_url`](crate::Doi::from_url)).
+///
+/// The message describes why the input was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDoiError {
+    /// The rejected DOI (after normalization) or URL.
+    pub(crate) input: String,
+    /// Why the input was rejected.
+    pub(crate) reason: &'static str,
+}
+
+impl fmt::Display for ParseDoiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid DOI {:?}: {}", self.input, self.reason)
+    }
+}
+
+impl Error for ParseDoiError {}
+
+/// Kind of a failed HTTP request, classified from the underlying `ureq::Error`.
+///
+/// The errors returned by [`Doi::resolve`](crate::Doi::resolve) and the metadata methods
+/// can be classified via [`Self::of`] to react differently to each kind of failure,
+/// e.g., retrying a timeout but not a DNS failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResolveErrorKind {
+    /// The request (connecting, reading, or writing) timed out.
+    Timeout,
+    /// The host name could not be resolved.
+    Dns,
+    /// The TLS handshake failed (e.g., an invalid certificate).
+    Tls,
+    /// The connection could not be established or was interrupted.
+    Connection,
+    /// The server responded with an error status code.
+    Status(u16),
+    /// Any other error (e.g., an invalid URL or a malformed response).
+    Other,
+}
+
+impl ResolveErrorKind {
+    /// Classifies an error returned by this crate.
+    ///
+    /// This accepts a [`DoiError`] (the same as [`DoiError::kind`]),
+    /// as well as a `ureq::Error` or `std::io::Error` (e.g., in a `Box<dyn Error>`).
+    /// Returns `None` if the error is not caused by an HTTP request
+    /// (e.g., the DOI is not set or the JSON metadata cannot be parsed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{Doi, ResolveErrorKind};
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve() {
+    ///     Ok(link) => println!("Resolved link: {}", link),
+    ///     Err(e) => match ResolveErrorKind::of(&e) {
+    ///         Some(ResolveErrorKind::Timeout) => eprintln!("Timed out, try again later"),
+    ///         Some(ResolveErrorKind::Status(404)) => eprintln!("DOI not found"),
+    ///         kind => eprintln!("Error ({:?}): {}", kind, e),
+    ///     },
+    /// }
+    /// let unset = Doi::default();
+    /// assert_eq!(ResolveErrorKind::of(&unset.get_doi().unwrap_err()), None);
+    /// ```
+    pub fn of(error: &(dyn Error + 'static)) -> Option<Self> {
+        if let Some(e) = error.downcast_ref::<DoiError>() {
+            return e.kind();
+        }
+        if let Some(e) = error.downcast_ref::<io::Error>() {
+            return Some(Self::from_io(e));
+        }
+        error.downcast_ref::<ureq::Error>().map(Self::from)
+    }
+
+    /// Classifies an I/O error from reading a response body.
+    fn from_io(error: &io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::TimedOut => Self::Timeout,
+            _ => Self::Connection,
+        }
+    }
+}
+
+impl From<&ureq::Error> for ResolveErrorKind {
+    fn from(error: &ureq::Error) -> Self {
+        let transport = match error {
+            ureq::Error::Status(code, _) => return Self::Status(*code),
+            ureq::Error::Transport(transport) => transport,
+        };
+        if io_error_kind(error) == Some(io::ErrorKind::TimedOut) {
+            return Self::Timeout;
+        }
+        match transport.kind() {
+            ureq::ErrorKind::Dns => Self::Dns,
+            ureq::ErrorKind::ConnectionFailed if is_tls(transport) => Self::Tls,
+            ureq::ErrorKind::ConnectionFailed | ureq::ErrorKind::ProxyConnect => Self::Connection,
+            ureq::ErrorKind::Io => match io_error_kind(error) {
+                Some(io::ErrorKind::InvalidData) => Self::Other,
+                _ => Self::Connection,
+            },
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Returns the kind of the first `io::Error` in the source chain of the error.
+fn io_error_kind(error: &(dyn Error + 'static)) -> Option<io::ErrorKind> {
+    let mut source = error.source();
+    while let Some(e) = source {
+        if let Some(io_error) = e.downcast_ref::<io::Error>() {
+            return Some(io_error.kind());
+        }
+        source = e.source();
+    }
+    None
+}
+
+/// Checks if a connection failure happened during the TLS handshake.
+///
+/// `ureq` does not expose the TLS error type, but labels the failure in its message,
+/// and the TLS backends report handshake errors as invalid data.
+fn is_tls(transport: &ureq::Transport) -> bool {
+    transport
+        .message()
+        .is_some_and(|message| message.to_ascii_lowercase().contains("tls"))
+        || transport
+            .source()
+            .and_then(|e| e.downcast_ref::<io::Error>())
+            .is_some_and(|e| e.kind() == io::ErrorKind::InvalidData)
+}
@@ -0,0 +1,645 @@
+use crate::{DoiMetadata, DoiMetadataDate, DoiMetadataType, JsonValue};
+use ureq::serde_json::{self, json, Map};
+
+/// Escapes the XML special characters in a string.
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Local formatters of the metadata.
+///
+/// These require the `formats` feature (enabled by default).
+impl DoiMetadata {
+    /// Formats the metadata as a Dublin Core record.
+    ///
+    /// The record is an `<oai_dc:dc>` element as used by the
+    /// [OAI-PMH](https://www.openarchives.org/OAI/openarchivesprotocol.html) protocol.
+    /// Fields that are not set are omitted.
+    ///
+    /// | Metadata | Dublin Core |
+    /// | --- | --- |
+    /// | `title` | `dc:title` |
+    /// | `authors` | `dc:creator` |
+    /// | `publisher` | `dc:publisher` |
+    /// | `r#type` | `dc:type` |
+    /// | `published` | `dc:date` (ISO 8601) |
+    /// | `doi` | `dc:identifier` |
+    /// | `language` | `dc:language` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataPerson, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.title = Some("Linear Transformations & HLS".to_string());
+    /// metadata.authors = Some(vec![DoiMetadataPerson {
+    ///     given: Some("Teddy".to_string()),
+    ///     family: Some("Jerry".to_string()),
+    ///     ..Default::default()
+    /// }]);
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// let xml = metadata.to_dublin_core_xml();
+    /// assert!(xml.starts_with("<oai_dc:dc "));
+    /// assert!(xml.contains("<dc:title>Linear Transformations &amp; HLS</dc:title>"));
+    /// assert!(xml.contains("<dc:creator>Teddy Jerry</dc:creator>"));
+    /// assert!(xml.contains("<dc:type>article-journal</dc:type>"));
+    /// assert!(xml.contains("<dc:identifier>https://doi.org/10.1109/TCSII.2024.3366282</dc:identifier>"));
+    /// assert!(xml.trim_end().ends_with("</oai_dc:dc>"));
+    /// ```
+    pub fn to_dublin_core_xml(&self) -> String {
+        let mut xml = String::from(concat!(
+            r#"<oai_dc:dc xmlns:oai_dc="http://www.openarchives.org/OAI/2.0/oai_dc/""#,
+            r#" xmlns:dc="http://purl.org/dc/elements/1.1/""#,
+            r#" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#,
+            r#" xsi:schemaLocation="http://www.openarchives.org/OAI/2.0/oai_dc/ http://www.openarchives.org/OAI/2.0/oai_dc.xsd">"#,
+            "\n",
+        ));
+        let mut element = |name: &str, value: &str| {
+            xml.push_str(&format!(
+                "  <dc:{0}>{1}</dc:{0}>\n",
+                name,
+                xml_escape(value)
+            ));
+        };
+        if let Some(title) = &self.title {
+            element("title", title);
+        }
+        for author in self.authors.iter().flatten() {
+            if let Ok(name) = author.full_name() {
+                element("creator", &name);
+            }
+        }
+        if let Some(publisher) = &self.publisher {
+            element("publisher", publisher);
+        }
+        if let Some(r#type) = &self.r#type {
+            element("type", r#type.as_str());
+        }
+        if let Some(date) = self
+            .published
+            .as_ref()
+            .and_then(DoiMetadataDate::to_iso8601)
+        {
+            element("date", &date);
+        }
+        element("identifier", &format!("https://doi.org/{}", self.doi));
+        if let Some(language) = &self.language {
+            element("language", language);
+        }
+        xml.push_str("</oai_dc:dc>\n");
+        xml
+    }
+
+    /// Converts the metadata to a CSL-JSON object.
+    ///
+    /// The object follows the [`csl-data.json`](https://github.com/citation-style-language/schema/blob/master/schemas/input/csl-data.json) schema,
+    /// built locally from the structured fields (no request is made).
+    /// Fields that are not set are omitted.
+    ///
+    /// | Metadata | CSL-JSON |
+    /// | --- | --- |
+    /// | `doi` | `DOI` |
+    /// | `r#type` | `type` |
+    /// | `title` | `title` |
+    /// | `container_title` | `container-title` |
+    /// | `short_container_title` | `container-title-short` |
+    /// | `volume`, `issue`, `page`, `publisher`, `language` | `volume`, `issue`, `page`, `publisher`, `language` |
+    /// | `url` | `URL` |
+    /// | `issn` | `ISSN` |
+    /// | `isbn` | `ISBN` |
+    /// | `event` | `event-title` |
+    /// | `number_of_pages` | `number-of-pages` |
+    /// | `published` | `issued` (`date-parts`, as precise as known) |
+    /// | `r#abstract` | `abstract` |
+    /// | `authors` | `author` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// let csl = metadata.to_csl_json();
+    /// assert_eq!(csl["DOI"], "10.1109/TCSII.2024.3366282");
+    /// assert_eq!(csl["type"], "article-journal");
+    /// assert!(csl["title"].is_null());
+    /// ```
+    pub fn to_csl_json(&self) -> JsonValue {
+        let mut csl = Map::new();
+        csl.insert("DOI".to_string(), self.doi.clone().into());
+        if let Some(r#type) = &self.r#type {
+            csl.insert("type".to_string(), r#type.as_str().into());
+        }
+        if let Some(title) = &self.title {
+            csl.insert("title".to_string(), title.clone().into());
+        }
+        if let Some(container_title) = &self.container_title {
+            csl.insert(
+                "container-title".to_string(),
+                container_title.clone().into(),
+            );
+        }
+        if let Some(short_container_title) = &self.short_container_title {
+            csl.insert(
+                "container-title-short".to_string(),
+                short_container_title.clone().into(),
+            );
+        }
+        for (key, value) in [
+            ("volume", &self.volume),
+            ("issue", &self.issue),
+            ("page", &self.page),
+            ("publisher", &self.publisher),
+            ("language", &self.language),
+        ] {
+            if let Some(value) = value {
+                csl.insert(key.to_string(), value.clone().into());
+            }
+        }
+        if let Some(url) = &self.url {
+            csl.insert("URL".to_string(), url.clone().into());
+        }
+        if let Some(issn) = &self.issn {
+            csl.insert("ISSN".to_string(), issn.clone().into());
+        }
+        if let Some(isbn) = &self.isbn {
+            csl.insert("ISBN".to_string(), isbn.clone().into());
+        }
+        if let Some(event) = &self.event {
+            csl.insert("event-title".to_string(), event.clone().into());
+        }
+        if let Some(number_of_pages) = self.number_of_pages {
+            csl.insert("number-of-pages".to_string(), number_of_pages.into());
+        }
+        if let Some(published) = &self.published {
+            let parts: Vec<u32> = [published.year, published.month, published.day]
+                .into_iter()
+                .map_while(|part| part)
+                .collect();
+            if !parts.is_empty() {
+                csl.insert("issued".to_string(), json!({ "date-parts": [parts] }));
+            }
+        }
+        if let Some(r#abstract) = &self.r#abstract {
+            csl.insert("abstract".to_string(), r#abstract.clone().into());
+        }
+        if let Some(authors) = &self.authors {
+            let authors = authors
+                .iter()
+                .map(|author| {
+                    let mut person = Map::new();
+                    let parts = [
+                        ("given", &author.given),
+                        ("family", &author.family),
+                        ("suffix", &author.suffix),
+                        ("literal", &author.literal),
+                        ("sequence", &author.sequence),
+                    ];
+                    for (key, value) in parts {
+                        if let Some(value) = value {
+                            person.insert(key.to_string(), value.clone().into());
+                        }
+                    }
+                    if let Some(affiliation) = &author.affiliation {
+                        let affiliation = affiliation
+                            .iter()
+                            .map(|name| serde_json::json!({ "name": name }))
+                            .collect();
+                        person.insert("affiliation".to_string(), JsonValue::Array(affiliation));
+                    }
+                    JsonValue::Object(person)
+                })
+                .collect();
+            csl.insert("author".to_string(), JsonValue::Array(authors));
+        }
+        JsonValue::Object(csl)
+    }
+
+    /// Serializes the metadata to a CSL-JSON string.
+    ///
+    /// This is the offline analog of [`Doi::metadata_json_string`](crate::Doi::metadata_json_string),
+    /// serializing the object of [`Self::to_csl_json`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pretty` - A `bool` representing whether to pretty-print the JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, JsonValue};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.title = Some("Flexible High-Level Synthesis Library for Linear Transformations".to_string());
+    /// let json = metadata.to_json_string(true);
+    /// assert!(json.contains('\n'));
+    /// let parsed: JsonValue = ureq::serde_json::from_str(&json).unwrap();
+    /// assert_eq!(parsed["DOI"], "10.1109/TCSII.2024.3366282");
+    /// assert!(!metadata.to_json_string(false).contains('\n'));
+    /// ```
+    pub fn to_json_string(&self, pretty: bool) -> String {
+        let csl = self.to_csl_json();
+        if pretty {
+            serde_json::to_string_pretty(&csl).unwrap_or_default()
+        } else {
+            csl.to_string()
+        }
+    }
+
+    /// Formats the metadata as an OpenURL context object in KEV format.
+    ///
+    /// The query string follows [Z39.88-2004](https://www.niso.org/publications/z3988-2004-r2010)
+    /// for link resolvers, using the book format for books and chapters and the journal format otherwise.
+    /// Fields that are not set are omitted, and all values are URL-encoded.
+    ///
+    /// | Metadata | OpenURL |
+    /// | --- | --- |
+    /// | `doi` | `rft_id` (`info:doi/<DOI>`) |
+    /// | `r#type` | `rft_val_fmt` and `rft.genre` |
+    /// | `title` | `rft.atitle` (`rft.btitle` for books) |
+    /// | `container_title` | `rft.jtitle` (`rft.btitle` for chapters) |
+    /// | `short_container_title` | `rft.stitle` (journal format only) |
+    /// | `authors` | `rft.au` (the first author also as `rft.aulast`/`rft.aufirst`) |
+    /// | `published` | `rft.date` (ISO 8601) |
+    /// | `publisher` | `rft.pub` |
+    /// | [`Self::primary_issn`] | `rft.issn` |
+    /// | `isbn` (first) | `rft.isbn` |
+    /// | `volume` | `rft.volume` |
+    /// | `issue` | `rft.issue` |
+    /// | `page` | `rft.spage` and `rft.epage` (see [`Self::page_range`]), or `rft.pages` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataPerson, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.title = Some("Flexible HLS Library".to_string());
+    /// metadata.container_title = Some("IEEE TCAS-II".to_string());
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// metadata.authors = Some(vec![DoiMetadataPerson {
+    ///     given: Some("Teddy".to_string()),
+    ///     family: Some("Jerry".to_string()),
+    ///     ..Default::default()
+    /// }]);
+    /// let openurl = metadata.to_openurl();
+    /// assert!(openurl.starts_with("url_ver=Z39.88-2004&"));
+    /// assert!(openurl.contains("&rft_id=info%3Adoi%2F10.1109%2FTCSII.2024.3366282"));
+    /// assert!(openurl.contains("&rft.atitle=Flexible+HLS+Library"));
+    /// assert!(openurl.contains("&rft.jtitle=IEEE+TCAS-II"));
+    /// assert!(openurl.contains("&rft.aulast=Jerry&rft.aufirst=Teddy"));
+    /// ```
+    pub fn to_openurl(&self) -> String {
+        let (format, genre) = match self.r#type {
+            Some(DoiMetadataType::Book) => ("book", "book"),
+            Some(DoiMetadataType::Chapter) => ("book", "bookitem"),
+            Some(
+                DoiMetadataType::Article
+                | DoiMetadataType::ArticleJournal
+                | DoiMetadataType::ArticleMagazine
+                | DoiMetadataType::ArticleNewspaper,
+            ) => ("journal", "article"),
+            Some(DoiMetadataType::PaperConference) => ("journal", "proceeding"),
+            Some(DoiMetadataType::Preprint) => ("journal", "preprint"),
+            _ => ("journal", "unknown"),
+        };
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        query
+            .append_pair("url_ver", "Z39.88-2004")
+            .append_pair("ctx_ver", "Z39.88-2004")
+            .append_pair("rft_val_fmt", &format!("info:ofi/fmt:kev:mtx:{}", format))
+            .append_pair("rft_id", &format!("info:doi/{}", self.doi))
+            .append_pair("rft.genre", genre);
+        let (title_key, container_key) = match genre {
+            "book" => ("rft.btitle", None),
+            "bookitem" => ("rft.atitle", Some("rft.btitle")),
+            _ => ("rft.atitle", Some("rft.jtitle")),
+        };
+        if let Some(title) = &self.title {
+            query.append_pair(title_key, title);
+        }
+        if let (Some(key), Some(container_title)) = (container_key, &self.container_title) {
+            query.append_pair(key, container_title);
+        }
+        if let (Some("rft.jtitle"), Some(short_container_title)) =
+            (container_key, &self.short_container_title)
+        {
+            query.append_pair("rft.stitle", short_container_title);
+        }
+        if let Some(first) = self.authors.as_ref().and_then(|authors| authors.first()) {
+            if let Some(family) = &first.family {
+                query.append_pair("rft.aulast", family);
+            }
+            if let Some(given) = &first.given {
+                query.append_pair("rft.aufirst", given);
+            }
+        }
+        for author in self.authors.iter().flatten() {
+            if let Ok(name) = author.full_name() {
+                query.append_pair("rft.au", &name);
+            }
+        }
+        if let Some(date) = self
+            .published
+            .as_ref()
+            .and_then(DoiMetadataDate::to_iso8601)
+        {
+            query.append_pair("rft.date", &date);
+        }
+        if let Some(publisher) = &self.publisher {
+            query.append_pair("rft.pub", publisher);
+        }
+        if let Some(issn) = self.primary_issn() {
+            query.append_pair("rft.issn", issn);
+        }
+        if let Some(isbn) = self.isbn.as_ref().and_then(|isbn| isbn.first()) {
+            query.append_pair("rft.isbn", isbn);
+        }
+        if let Some(volume) = &self.volume {
+            query.append_pair("rft.volume", volume);
+        }
+        if let Some(issue) = &self.issue {
+            query.append_pair("rft.issue", issue);
+        }
+        match (self.page_range(), &self.page) {
+            (Some((start, end)), _) => {
+                query
+                    .append_pair("rft.spage", &start)
+                    .append_pair("rft.epage", &end);
+            }
+            (None, Some(page)) => {
+                query.append_pair("rft.pages", page);
+            }
+            (None, None) => {}
+        }
+        query.finish()
+    }
+
+    /// Formats the metadata as a [RIS](https://en.wikipedia.org/wiki/RIS_(file_format)) record.
+    ///
+    /// The record starts with the `TY` reference type and ends with `ER`.
+    /// Fields that are not set are omitted.
+    ///
+    /// | Metadata | RIS |
+    /// | --- | --- |
+    /// | `r#type` | `TY` (`GEN` if unknown) |
+    /// | `authors` | `AU` (one line per author, "Family, Given") |
+    /// | `title` | `TI` |
+    /// | `container_title` | `JO` for articles, `T2` otherwise |
+    /// | `short_container_title` | `J2` |
+    /// | `volume` | `VL` |
+    /// | `issue` | `IS` |
+    /// | `page` | `SP` and `EP` (see [`Self::page_range`]), or `SP` alone |
+    /// | `publisher` | `PB` |
+    /// | `issn` and `isbn` | `SN` (one line per ISSN or ISBN) |
+    /// | `subjects` | `KW` (one line per subject) |
+    /// | `language` | `LA` |
+    /// | [`Self::year`] | `PY` |
+    /// | `published` | `DA` ("YYYY/MM/DD", as precise as known) |
+    /// | `doi` | `DO` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataDate, DoiMetadataPerson, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// metadata.title = Some("Flexible High-Level Synthesis Library for Linear Transformations".to_string());
+    /// metadata.container_title = Some("IEEE Transactions on Circuits and Systems II: Express Briefs".to_string());
+    /// metadata.authors = Some(vec![DoiMetadataPerson {
+    ///     given: Some("Teddy".to_string()),
+    ///     family: Some("Jerry".to_string()),
+    ///     ..Default::default()
+    /// }]);
+    /// metadata.published_other = Some(DoiMetadataDate { year: Some(2024), ..Default::default() });
+    /// assert_eq!(
+    ///     metadata.to_ris(),
+    ///     "TY  - JOUR\n\
+    ///      AU  - Jerry, Teddy\n\
+    ///      TI  - Flexible High-Level Synthesis Library for Linear Transformations\n\
+    ///      JO  - IEEE Transactions on Circuits and Systems II: Express Briefs\n\
+    ///      PY  - 2024\n\
+    ///      DO  - 10.1109/TCSII.2024.3366282\n\
+    ///      ER  - \n"
+    /// );
+    /// ```
+    pub fn to_ris(&self) -> String {
+        let (tag, journal) = match self.r#type {
+            Some(DoiMetadataType::Article | DoiMetadataType::ArticleJournal) => ("JOUR", true),
+            Some(DoiMetadataType::ArticleMagazine) => ("MGZN", true),
+            Some(DoiMetadataType::ArticleNewspaper) => ("NEWS", true),
+            Some(DoiMetadataType::Bill) => ("BILL", false),
+            Some(DoiMetadataType::Book) => ("BOOK", false),
+            Some(DoiMetadataType::Chapter) => ("CHAP", false),
+            Some(DoiMetadataType::Dataset) => ("DATA", false),
+            Some(DoiMetadataType::Map) => ("MAP", false),
+            Some(DoiMetadataType::PaperConference) => ("CPAPER", false),
+            Some(DoiMetadataType::Patent) => ("PAT", false),
+            Some(DoiMetadataType::Preprint) => ("UNPB", false),
+            Some(DoiMetadataType::Report) => ("RPRT", false),
+            Some(DoiMetadataType::Software) => ("COMP", false),
+            Some(DoiMetadataType::Standard) => ("STAND", false),
+            Some(DoiMetadataType::Thesis) => ("THES", false),
+            Some(DoiMetadataType::Webpage) => ("ELEC", false),
+            _ => ("GEN", false),
+        };
+        let mut ris = String::new();
+        let mut line = |tag: &str, value: &str| ris.push_str(&format!("{}  - {}\n", tag, value));
+        line("TY", tag);
+        for author in self.authors.iter().flatten() {
+            if let Some(name) = author.family_given() {
+                line("AU", &name);
+            }
+        }
+        if let Some(title) = &self.title {
+            line("TI", title);
+        }
+        if let Some(container_title) = &self.container_title {
+            line(if journal { "JO" } else { "T2" }, container_title);
+        }
+        if let Some(short_container_title) = &self.short_container_title {
+            line("J2", short_container_title);
+        }
+        if let Some(volume) = &self.volume {
+            line("VL", volume);
+        }
+        if let Some(issue) = &self.issue {
+            line("IS", issue);
+        }
+        match (self.page_range(), &self.page) {
+            (Some((start, end)), _) => {
+                line("SP", &start);
+                line("EP", &end);
+            }
+            (None, Some(page)) => line("SP", page),
+            (None, None) => {}
+        }
+        if let Some(publisher) = &self.publisher {
+            line("PB", publisher);
+        }
+        for number in self.issn.iter().chain(&self.isbn).flatten() {
+            line("SN", number);
+        }
+        for subject in self.subjects.iter().flatten() {
+            line("KW", subject);
+        }
+        if let Some(language) = &self.language {
+            line("LA", language);
+        }
+        if let Some(year) = self.year() {
+            line("PY", &year.to_string());
+        }
+        if let Some(date) = self
+            .published
+            .as_ref()
+            .and_then(DoiMetadataDate::to_iso8601)
+        {
+            line("DA", &date.replace('-', "/"));
+        }
+        line("DO", &self.doi);
+        line("ER", "");
+        ris
+    }
+
+    /// Formats the metadata as an EndNote XML record (for EndNote's XML import).
+    ///
+    /// The record is a single `<record>` in `<xml><records>`. Fields that are not set are omitted.
+    ///
+    /// | Metadata | EndNote XML |
+    /// | --- | --- |
+    /// | `r#type` | `ref-type` (`Generic` if unknown) |
+    /// | `authors` | `contributors/authors/author` ("Family, Given") |
+    /// | `title` | `titles/title` |
+    /// | `container_title` | `titles/secondary-title` |
+    /// | `short_container_title` | `titles/alt-title` |
+    /// | `page` | `pages` |
+    /// | `volume` | `volume` |
+    /// | `issue` | `number` |
+    /// | [`Self::year`] | `dates/year` |
+    /// | `publisher` | `publisher` |
+    /// | `isbn` (first), or else [`Self::primary_issn`] | `isbn` (EndNote's element for both ISBN and ISSN) |
+    /// | `doi` | `electronic-resource-num` and `urls/related-urls/url` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataPerson, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// metadata.title = Some("Linear Transformations & HLS".to_string());
+    /// metadata.authors = Some(vec![DoiMetadataPerson {
+    ///     given: Some("Teddy".to_string()),
+    ///     family: Some("Jerry".to_string()),
+    ///     ..Default::default()
+    /// }]);
+    /// let xml = metadata.to_endnote_xml();
+    /// assert!(xml.contains(r#"<ref-type name="Journal Article">17</ref-type>"#));
+    /// assert!(xml.contains("<titles><title>Linear Transformations &amp; HLS</title></titles>"));
+    /// assert!(xml.contains("<contributors><authors><author>Jerry, Teddy</author></authors></contributors>"));
+    /// assert!(xml.contains("<electronic-resource-num>10.1109/TCSII.2024.3366282</electronic-resource-num>"));
+    /// assert!(xml.trim_end().ends_with("</records></xml>"));
+    ///
+    /// let xml = DoiMetadata::default().to_endnote_xml();
+    /// assert!(!xml.contains("electronic-resource-num") && !xml.contains("doi.org"));
+    /// ```
+    pub fn to_endnote_xml(&self) -> String {
+        let (name, number) = match self.r#type {
+            Some(DoiMetadataType::Article | DoiMetadataType::ArticleJournal) => {
+                ("Journal Article", 17)
+            }
+            Some(DoiMetadataType::ArticleMagazine) => ("Magazine Article", 19),
+            Some(DoiMetadataType::ArticleNewspaper) => ("Newspaper Article", 23),
+            Some(DoiMetadataType::Bill) => ("Bill", 4),
+            Some(DoiMetadataType::Book) => ("Book", 6),
+            Some(DoiMetadataType::Chapter) => ("Book Section", 5),
+            Some(DoiMetadataType::Dataset) => ("Dataset", 59),
+            Some(DoiMetadataType::Map) => ("Map", 20),
+            Some(DoiMetadataType::PaperConference) => ("Conference Paper", 47),
+            Some(DoiMetadataType::Patent) => ("Patent", 25),
+            Some(DoiMetadataType::Preprint) => ("Unpublished Work", 34),
+            Some(DoiMetadataType::Report) => ("Report", 27),
+            Some(DoiMetadataType::Software) => ("Computer Program", 9),
+            Some(DoiMetadataType::Standard) => ("Standard", 58),
+            Some(DoiMetadataType::Thesis) => ("Thesis", 32),
+            Some(DoiMetadataType::Webpage) => ("Web Page", 12),
+            _ => ("Generic", 13),
+        };
+        let element = |name: &str, value: &str| format!("<{0}>{1}</{0}>", name, xml_escape(value));
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<xml><records>");
+        xml.push_str("<record>");
+        xml.push_str(&format!(
+            "<ref-type name=\"{}\">{}</ref-type>",
+            name, number
+        ));
+        let authors: String = self
+            .authors
+            .iter()
+            .flatten()
+            .filter_map(|author| author.family_given())
+            .map(|name| element("author", &name))
+            .collect();
+        if !authors.is_empty() {
+            xml.push_str(&format!(
+                "<contributors><authors>{}</authors></contributors>",
+                authors
+            ));
+        }
+        let titles: String = [
+            ("title", &self.title),
+            ("secondary-title", &self.container_title),
+            ("alt-title", &self.short_container_title),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some(element(name, value.as_ref()?)))
+        .collect();
+        if !titles.is_empty() {
+            xml.push_str(&format!("<titles>{}</titles>", titles));
+        }
+        for (name, value) in [
+            ("pages", &self.page),
+            ("volume", &self.volume),
+            ("number", &self.issue),
+        ] {
+            if let Some(value) = value {
+                xml.push_str(&element(name, value));
+            }
+        }
+        if let Some(year) = self.year() {
+            xml.push_str(&format!(
+                "<dates>{}</dates>",
+                element("year", &year.to_string())
+            ));
+        }
+        if let Some(publisher) = &self.publisher {
+            xml.push_str(&element("publisher", publisher));
+        }
+        if let Some(number) = self
+            .isbn
+            .as_ref()
+            .and_then(|isbn| isbn.first())
+            .map(String::as_str)
+            .or_else(|| self.primary_issn())
+        {
+            xml.push_str(&element("isbn", number));
+        }
+        if !self.doi.is_empty() {
+            xml.push_str(&element("electronic-resource-num", &self.doi));
+            xml.push_str(&format!(
+                "<urls><related-urls>{}</related-urls></urls>",
+                element("url", &format!("https://doi.org/{}", self.doi))
+            ));
+        }
+        xml.push_str("</record>");
+        xml.push_str("</records></xml>\n");
+        xml
+    }
+}
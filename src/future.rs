@@ -0,0 +1,119 @@
+use crate::{Doi, DoiError};
+use std::future::Future;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+/// Future of a blocking call running on its own thread.
+///
+/// The future does not depend on an async runtime, so it can be awaited on any executor.
+/// The call runs to completion even if the future is dropped.
+/// This requires the `async` feature.
+#[derive(Debug)]
+pub struct DoiFuture<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+/// Shared state of a [`DoiFuture`] and its thread.
+#[derive(Debug)]
+struct State<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> DoiFuture<Result<T, DoiError>> {
+    /// Runs `call` on a new thread, completing the future with its result.
+    ///
+    /// If `call` panics, the future completes with an error instead of pending forever.
+    fn spawn<F: FnOnce() -> Result<T, DoiError> + Send + 'static>(call: F) -> Self {
+        let state = Arc::new(Mutex::new(State {
+            result: None,
+            waker: None,
+        }));
+        let shared = state.clone();
+        thread::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(call))
+                .unwrap_or_else(|_| Err(io::Error::other("The call panicked").into()));
+            let mut state = shared.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        Self { state }
+    }
+}
+
+impl<T> Future for DoiFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Asynchronous variants of the blocking methods.
+///
+/// Each call clones the [`Doi`] (sharing its agent and cache) and runs the blocking method
+/// on a new thread, so the executor is not blocked while waiting for the response.
+/// This requires the `async` feature.
+impl Doi {
+    /// Asynchronously resolves the DOI and returns the resolved URL (see [`Self::resolve`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same [`DoiError`] as [`Self::resolve`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use doi::Doi;
+    /// # async fn run() {
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve_async().await {
+    ///     Ok(link) => println!("Resolved link: {}", link),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// # }
+    /// ```
+    pub fn resolve_async(&self) -> DoiFuture<Result<String, DoiError>> {
+        let doi = self.clone();
+        DoiFuture::spawn(move || doi.resolve())
+    }
+
+    /// Asynchronously fetches metadata for the DOI (see [`Self::metadata`]).
+    ///
+    /// This requires the `metadata` feature as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same [`DoiError`] as [`Self::metadata`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use doi::Doi;
+    /// # async fn run() {
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.metadata_async().await {
+    ///     Ok(metadata) => println!("Paper Title: {}", metadata.title.unwrap_or("<unknown>".to_string())),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "metadata")]
+    pub fn metadata_async(&self) -> DoiFuture<Result<crate::DoiMetadata, DoiError>> {
+        let doi = self.clone();
+        DoiFuture::spawn(move || doi.metadata())
+    }
+}
@@ -1,5 +1,9 @@
-use crate::Doi;
-use std::error::Error;
+use crate::{Doi, DoiError};
+use flate2::read::GzDecoder;
+use std::convert::Infallible;
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 pub use ureq::serde_json::Value as JsonValue;
 
 /// Metadata for a DOI.
@@ -11,10 +15,74 @@ pub struct DoiMetadata {
     pub doi: String,
     /// Title of the document.
     pub title: Option<String>,
+    /// Language tag of the title (e.g., `en`), if given by the source (e.g., DataCite `titles[].lang`).
+    pub title_language: Option<String>,
     /// Author(s) of the document.
     pub authors: Option<Vec<DoiMetadataPerson>>,
     /// Type of the document (e.g., journal, conference).
     pub r#type: Option<DoiMetadataType>,
+    /// DOI of the container (e.g., the journal or proceedings) of the document.
+    pub container_doi: Option<String>,
+    /// Title of the container (e.g., the journal or proceedings) of the document.
+    pub container_title: Option<String>,
+    /// Abbreviated title of the container (e.g., `IEEE Trans. Circuits Syst. II`).
+    pub short_container_title: Option<String>,
+    /// Volume of the container (e.g., of a journal) containing the document.
+    pub volume: Option<String>,
+    /// Issue of the container (e.g., of a journal) containing the document.
+    pub issue: Option<String>,
+    /// Page(s) of the document, e.g., `123-145`, `S1-S5`, or an article number like `e12345`.
+    pub page: Option<String>,
+    /// Publisher of the document (e.g., IEEE, ACM, Springer).
+    pub publisher: Option<String>,
+    /// ISSN(s) of the container (e.g., the print and electronic ISSNs of a journal), in deposited order.
+    pub issn: Option<Vec<String>>,
+    /// ISBN(s) of the document or its container (e.g., of a book or proceedings), as bare ISBNs.
+    pub isbn: Option<Vec<String>>,
+    /// Name of the event (e.g., the conference) related to the document.
+    pub event: Option<String>,
+    /// Subtype refining the type of the document (e.g., `preprint`, `letter`).
+    pub subtype: Option<String>,
+    /// Clinical trial(s) registered for the document, as `(registry, number)` pairs.
+    pub clinical_trials: Option<Vec<(String, String)>>,
+    /// Date of publication (the `issued` date), possibly partial (e.g., only the year).
+    pub published: Option<DoiMetadataDate>,
+    /// Date of publication in another form (e.g., a publisher-specific date).
+    pub published_other: Option<DoiMetadataDate>,
+    /// Date when the metadata record was first deposited (e.g., with Crossref),
+    /// unrelated to the publication date.
+    pub created: Option<DoiMetadataDate>,
+    /// Date when the metadata record was last indexed (e.g., by Crossref).
+    pub indexed: Option<DoiMetadataDate>,
+    /// Number of pages of the document (e.g., a book).
+    pub number_of_pages: Option<u32>,
+    /// Title of the group of documents (e.g., a special issue or collection).
+    pub group_title: Option<String>,
+    /// Subject(s) of the document (e.g., `Electrical and Electronic Engineering`), without repeats.
+    pub subjects: Option<Vec<String>>,
+    /// Language of the document as the raw code given by the source (e.g., `de` or `zh-CN`).
+    pub language: Option<String>,
+    /// URL registered for the document (the JSON `URL` field).
+    ///
+    /// This is the link deposited with the metadata, taken without a further request.
+    /// It may differ from the URL returned by [`Doi::resolve`](crate::Doi::resolve),
+    /// which follows all redirects to the final landing page:
+    /// Crossref, e.g., registers the `https://doi.org/<DOI>` link itself,
+    /// and publishers often redirect their registered link (e.g., to a regional site).
+    pub url: Option<String>,
+    /// Link(s) to the full text of the document (e.g., a PDF).
+    pub links: Option<Vec<DoiMetadataLink>>,
+    /// License(s) of the document (e.g., a Creative Commons license), as deposited by the registrant.
+    pub license: Option<Vec<DoiMetadataLicense>>,
+    /// Reference(s) cited by the document, in the deposited order (see [`Self::referenced_dois`]).
+    pub references: Option<Vec<DoiMetadataReference>>,
+    /// Number of works citing the document, as counted by Crossref (`is-referenced-by-count`).
+    pub referenced_by_count: Option<u64>,
+    /// Abstract of the document, possibly with JATS markup (see [`Self::abstract_text`]).
+    pub r#abstract: Option<String>,
+    /// Abstract(s) of the document as `(language, text)` pairs, e.g., from DataCite `descriptions`
+    /// in several languages, with `r#abstract` as the preferred one.
+    pub abstracts: Option<Vec<(Option<String>, String)>>,
 }
 
 /// Metadata for a person.
@@ -23,11 +91,22 @@ pub struct DoiMetadataPerson {
     pub given: Option<String>,
     pub family: Option<String>,
     pub suffix: Option<String>,
+    /// Name that is not split into parts (e.g., an organization).
+    pub literal: Option<String>,
+    /// Position in the author list as deposited (`first` or `additional` in Crossref).
+    pub sequence: Option<String>,
+    /// Name(s) of the affiliated institution(s).
+    pub affiliation: Option<Vec<String>>,
 }
 
 impl DoiMetadataPerson {
     /// Returns the full name of the person.
     ///
+    /// The name parts are joined given-first,
+    /// falling back to the literal name if there are no parts.
+    /// Empty or blank parts are skipped, so a mononym stored in any one field
+    /// (e.g., only `given` or only `family`) is returned alone.
+    ///
     /// # Errors
     ///
     /// Returns `Err(())` if the person's name is not set (all fields empty).
@@ -40,30 +119,214 @@ impl DoiMetadataPerson {
     ///      given: Some("Teddy".to_string()),
     ///      family: Some("Jerry".to_string()),
     ///      suffix: Some("Jr.".to_string()),
+    ///      ..Default::default()
     /// };
     /// assert_eq!(person.full_name().unwrap(), "Teddy Jerry Jr.".to_string());
+    /// let mononym = DoiMetadataPerson {
+    ///     given: Some("Plato".to_string()),
+    ///     family: Some("".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(mononym.full_name().unwrap(), "Plato".to_string());
     /// let no_name = DoiMetadataPerson::default();
     /// assert_eq!(no_name.full_name().is_ok(), false);
     /// ```
+    #[allow(clippy::result_unit_err)]
     pub fn full_name(&self) -> Result<String, ()> {
-        match (&self.given, &self.family, &self.suffix) {
-            (Some(given), Some(family), Some(suffix)) => {
-                Ok(format!("{} {} {}", given, family, suffix))
-            }
-            (Some(given), Some(family), None) => Ok(format!("{} {}", given, family)),
-            (Some(given), None, Some(suffix)) => Ok(format!("{} {}", given, suffix)),
-            (Some(given), None, None) => Ok(given.to_string()),
-            (None, Some(family), Some(suffix)) => Ok(format!("{} {}", family, suffix)),
-            (None, Some(family), None) => Ok(family.to_string()),
-            (None, None, Some(suffix)) => Ok(suffix.to_string()),
-            _ => Err(()),
+        let parts: Vec<_> = [&self.given, &self.family]
+            .into_iter()
+            .filter_map(part)
+            .collect();
+        if parts.is_empty() {
+            return self.literal_or_suffix().ok_or(());
+        }
+        Ok(parts
+            .into_iter()
+            .chain(part(&self.suffix))
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    /// Returns the name of the person in the "Family, Given" form.
+    ///
+    /// The suffix is appended as another comma-separated part.
+    /// If only one of the family and given names is available, it is returned alone,
+    /// falling back to the literal name.
+    /// Returns `None` if the person's name is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doi::DoiMetadataPerson;
+    /// let person = DoiMetadataPerson {
+    ///     given: Some("Teddy".to_string()),
+    ///     family: Some("Zhao".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(person.family_given(), Some("Zhao, Teddy".to_string()));
+    /// let family_only = DoiMetadataPerson {
+    ///     family: Some("Zhao".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(family_only.family_given(), Some("Zhao".to_string()));
+    /// let organization = DoiMetadataPerson {
+    ///     literal: Some("IEEE Circuits and Systems Society".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(organization.family_given(), Some("IEEE Circuits and Systems Society".to_string()));
+    /// assert_eq!(DoiMetadataPerson::default().family_given(), None);
+    /// ```
+    pub fn family_given(&self) -> Option<String> {
+        let parts: Vec<_> = [&self.family, &self.given]
+            .into_iter()
+            .filter_map(part)
+            .collect();
+        if parts.is_empty() {
+            return self.literal_or_suffix();
+        }
+        Some(
+            parts
+                .into_iter()
+                .chain(part(&self.suffix))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+
+    /// Returns the literal name, or the suffix as the last resort, for a person without name parts.
+    fn literal_or_suffix(&self) -> Option<String> {
+        part(&self.literal)
+            .or_else(|| part(&self.suffix))
+            .map(|name| name.to_string())
+    }
+}
+
+/// Returns the trimmed name part, or `None` if it is not set or blank.
+fn part(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+}
+
+/// Date in the metadata, possibly partial (e.g., only the year).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DoiMetadataDate {
+    pub year: Option<u32>,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+}
+
+impl DoiMetadataDate {
+    /// Formats the date in ISO 8601 as precise as it is known,
+    /// i.e., `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`.
+    ///
+    /// A day without a month is ignored.
+    /// Returns `None` if the year is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadataDate;
+    /// let mut date = DoiMetadataDate { year: Some(2024), ..Default::default() };
+    /// assert_eq!(date.to_iso8601().as_deref(), Some("2024"));
+    /// date.month = Some(2);
+    /// assert_eq!(date.to_iso8601().as_deref(), Some("2024-02"));
+    /// date.day = Some(15);
+    /// assert_eq!(date.to_iso8601().as_deref(), Some("2024-02-15"));
+    /// assert_eq!(DoiMetadataDate { month: Some(2), ..Default::default() }.to_iso8601(), None);
+    /// ```
+    pub fn to_iso8601(&self) -> Option<String> {
+        let year = self.year?;
+        Some(match (self.month, self.day) {
+            (Some(month), Some(day)) => format!("{:04}-{:02}-{:02}", year, month, day),
+            (Some(month), None) => format!("{:04}-{:02}", year, month),
+            (None, _) => format!("{:04}", year),
+        })
+    }
+}
+
+/// Metadata memoized by [`Doi::metadata_lazy`].
+///
+/// Cloning copies the metadata memoized so far (which is shared via `Arc`).
+#[derive(Debug, Default)]
+pub(crate) struct LazyMetadata(Mutex<Option<Arc<DoiMetadata>>>);
+
+impl Clone for LazyMetadata {
+    fn clone(&self) -> Self {
+        let memo = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        Self(Mutex::new(memo.clone()))
+    }
+}
+
+/// Format of the JSON metadata negotiated with the registration agency.
+///
+/// See [`DoiBuilder::prefer_format`](crate::DoiBuilder::prefer_format).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MetadataFormat {
+    /// Plain JSON (`application/json`), as used by [`Doi::metadata`].
+    #[default]
+    Json,
+    /// CSL-JSON (`application/vnd.citationstyles.csl+json`).
+    CslJson,
+}
+
+impl MetadataFormat {
+    /// Returns the MIME type sent in the `Accept` header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::MetadataFormat;
+    /// assert_eq!(MetadataFormat::CslJson.accept(), "application/vnd.citationstyles.csl+json");
+    /// ```
+    pub fn accept(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::CslJson => "application/vnd.citationstyles.csl+json",
         }
     }
 }
 
+/// Link to the full text of a document, as deposited by the registrant.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DoiMetadataLink {
+    /// URL of the full text (which may require a subscription).
+    pub url: String,
+    /// MIME type of the linked resource (e.g., `application/pdf`).
+    pub content_type: Option<String>,
+    /// Version of the linked resource (e.g., `vor` for the version of record, `am` for the accepted manuscript).
+    pub content_version: Option<String>,
+    /// Intended use of the link (e.g., `text-mining`, `similarity-checking`, `unspecified`).
+    pub intended_application: Option<String>,
+}
+
+/// License of a document, as deposited by the registrant.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DoiMetadataLicense {
+    /// URL of the license (e.g., `https://creativecommons.org/licenses/by/4.0/`).
+    pub url: String,
+    /// Version of the document the license applies to (e.g., `vor`, `am`, or `tdm`).
+    pub content_version: Option<String>,
+    /// Date from which the license applies (e.g., after an embargo).
+    pub start: Option<DoiMetadataDate>,
+}
+
+/// Reference cited by a document, as deposited by the registrant.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DoiMetadataReference {
+    /// DOI of the referenced document, if known.
+    pub doi: Option<String>,
+    /// Key of the reference within the document (e.g., `10.1109/TCSII.2024.3366282_ref1`).
+    pub key: Option<String>,
+    /// Citation of the reference as plain text, if deposited unstructured.
+    pub unstructured: Option<String>,
+}
+
 /// Metadata type for a DOI.
 ///
-/// Reference: [`csl-data.json`](https://github.com/citation-style-language/schema/blob/e3ce254a72c4470a5ed3b9d23b428017d25674e9/schemas/input/csl-data.json#L9-L58)
+/// Reference: [`csl-data.json`](https://github.com/citation-style-language/schema/blob/e3ce254a72c4470a5ed3b9d23b428017d25674e9/schemas/input/csl-data.json#L9-L58),
+/// plus the `preprint` type added in later CSL versions.
 #[derive(Debug, Clone, PartialEq)]
 pub enum DoiMetadataType {
     Article,
@@ -100,6 +363,7 @@ pub enum DoiMetadataType {
     PersonalCommunication,
     Post,
     PostWeblog,
+    Preprint,
     Regulation,
     Report,
     Review,
@@ -162,6 +426,7 @@ impl DoiMetadataType {
             "personal_communication" => Self::PersonalCommunication,
             "post" => Self::Post,
             "post-weblog" => Self::PostWeblog,
+            "preprint" => Self::Preprint,
             "regulation" => Self::Regulation,
             "report" => Self::Report,
             "review" => Self::Review,
@@ -178,6 +443,16 @@ impl DoiMetadataType {
     }
 
     /// Returns the DOI metadata type as a string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadataType;
+    /// let preprint = DoiMetadataType::new("preprint");
+    /// assert_eq!(preprint, DoiMetadataType::Preprint);
+    /// assert_eq!(preprint.as_str(), "preprint");
+    /// assert_eq!(DoiMetadataType::new(DoiMetadataType::ArticleJournal.as_str()), DoiMetadataType::ArticleJournal);
+    /// ```
     pub fn as_str(&self) -> &str {
         match self {
             Self::Article => "article",
@@ -214,6 +489,7 @@ impl DoiMetadataType {
             Self::PersonalCommunication => "personal_communication",
             Self::Post => "post",
             Self::PostWeblog => "post-weblog",
+            Self::Preprint => "preprint",
             Self::Regulation => "regulation",
             Self::Report => "report",
             Self::Review => "review",
@@ -228,6 +504,55 @@ impl DoiMetadataType {
             Self::MISC(s) => s,
         }
     }
+
+    /// Checks if a document of this type is published in a container,
+    /// so that a citation needs the container title (e.g., the journal or proceedings).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doi::DoiMetadataType;
+    /// assert!(DoiMetadataType::ArticleJournal.requires_container());
+    /// assert!(DoiMetadataType::PaperConference.requires_container());
+    /// assert!(DoiMetadataType::Chapter.requires_container());
+    /// assert!(!DoiMetadataType::Book.requires_container());
+    /// assert!(!DoiMetadataType::Dataset.requires_container());
+    /// assert!(!DoiMetadataType::MISC("other".to_string()).requires_container());
+    /// ```
+    pub fn requires_container(&self) -> bool {
+        matches!(
+            self,
+            Self::ArticleJournal
+                | Self::ArticleMagazine
+                | Self::ArticleNewspaper
+                | Self::Chapter
+                | Self::Entry
+                | Self::EntryDictionary
+                | Self::EntryEncyclopedia
+                | Self::PaperConference
+                | Self::Review
+                | Self::ReviewBook
+        )
+    }
+}
+
+impl FromStr for DoiMetadataType {
+    type Err = Infallible;
+
+    /// Parses a type string via [`DoiMetadataType::new`], which never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadataType;
+    /// let journal: DoiMetadataType = "article-journal".parse().unwrap();
+    /// assert_eq!(journal, DoiMetadataType::ArticleJournal);
+    /// assert_eq!("preprint".parse(), Ok(DoiMetadataType::Preprint));
+    /// assert_eq!("unknown".parse(), Ok(DoiMetadataType::MISC("unknown".to_string())));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
 }
 
 impl DoiMetadata {
@@ -235,11 +560,1076 @@ impl DoiMetadata {
     pub fn new(doi: String) -> Self {
         Self {
             doi,
-            title: None,
-            authors: None,
-            r#type: None,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the type of the document, or [`DoiMetadataType::Document`] if it is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// assert_eq!(metadata.type_or_default(), DoiMetadataType::Document);
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// assert_eq!(metadata.type_or_default(), DoiMetadataType::ArticleJournal);
+    /// ```
+    pub fn type_or_default(&self) -> DoiMetadataType {
+        self.type_or(DoiMetadataType::Document)
+    }
+
+    /// Returns the type of the document, or `default` if it is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// assert_eq!(metadata.type_or(DoiMetadataType::Article), DoiMetadataType::Article);
+    /// ```
+    pub fn type_or(&self, default: DoiMetadataType) -> DoiMetadataType {
+        self.r#type.clone().unwrap_or(default)
+    }
+
+    /// Checks if the document is a correction, based on its subtype.
+    ///
+    /// The subtypes `correction`, `corrigendum`, and `erratum` (case-insensitive) are recognized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadata;
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// assert!(!metadata.is_correction());
+    /// metadata.subtype = Some("Erratum".to_string());
+    /// assert!(metadata.is_correction());
+    /// metadata.subtype = Some("letter".to_string());
+    /// assert!(!metadata.is_correction());
+    /// ```
+    pub fn is_correction(&self) -> bool {
+        const CORRECTIONS: [&str; 3] = ["correction", "corrigendum", "erratum"];
+        self.subtype.as_deref().is_some_and(|subtype| {
+            CORRECTIONS
+                .iter()
+                .any(|correction| subtype.eq_ignore_ascii_case(correction))
+        })
+    }
+
+    /// Checks if the document is a journal article, i.e., of the type [`DoiMetadataType::ArticleJournal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// assert!(!metadata.is_journal_article());
+    /// metadata.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// assert!(metadata.is_journal_article());
+    /// assert!(!metadata.is_conference_paper());
+    /// ```
+    pub fn is_journal_article(&self) -> bool {
+        self.r#type == Some(DoiMetadataType::ArticleJournal)
+    }
+
+    /// Checks if the document is a conference paper, i.e., of the type [`DoiMetadataType::PaperConference`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1145/3643832.3661865".to_string());
+    /// assert!(!metadata.is_conference_paper());
+    /// metadata.r#type = Some(DoiMetadataType::PaperConference);
+    /// assert!(metadata.is_conference_paper());
+    /// assert!(!metadata.is_journal_article());
+    /// ```
+    pub fn is_conference_paper(&self) -> bool {
+        self.r#type == Some(DoiMetadataType::PaperConference)
+    }
+
+    /// Checks if the document is a book, i.e., of the type [`DoiMetadataType::Book`].
+    ///
+    /// A chapter of a book ([`DoiMetadataType::Chapter`]) is not a book itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let mut metadata = DoiMetadata::new("10.1007/978-3-030-12345-6".to_string());
+    /// metadata.r#type = Some(DoiMetadataType::Book);
+    /// assert!(metadata.is_book());
+    /// metadata.r#type = Some(DoiMetadataType::Chapter);
+    /// assert!(!metadata.is_book());
+    /// ```
+    pub fn is_book(&self) -> bool {
+        self.r#type == Some(DoiMetadataType::Book)
+    }
+
+    /// Returns the author names as a list truncated to `max` names.
+    ///
+    /// The full names (see [`DoiMetadataPerson::full_name`]) are joined by `", "`.
+    /// If there are more than `max` authors, only the first `max` are listed,
+    /// followed by the `et_al` marker (e.g., `"et al."`).
+    /// Authors without a name are skipped.
+    /// Returns `None` if there are no authors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataPerson};
+    /// let person = |family: &str| DoiMetadataPerson {
+    ///     family: Some(family.to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let mut metadata = DoiMetadata::new("10.1/x".to_string());
+    /// assert_eq!(metadata.authors_truncated(3, "et al."), None);
+    /// metadata.authors = Some(vec![person("A"), person("B")]);
+    /// assert_eq!(metadata.authors_truncated(3, "et al."), Some("A, B".to_string()));
+    /// metadata.authors = Some(vec![person("A"), person("B"), person("C"), person("D")]);
+    /// assert_eq!(metadata.authors_truncated(3, "et al."), Some("A, B, C, et al.".to_string()));
+    /// assert_eq!(metadata.authors_truncated(1, "u. a."), Some("A, u. a.".to_string()));
+    /// ```
+    pub fn authors_truncated(&self, max: usize, et_al: &str) -> Option<String> {
+        let names: Vec<_> = self
+            .authors
+            .iter()
+            .flatten()
+            .filter_map(|author| author.full_name().ok())
+            .collect();
+        if names.is_empty() {
+            return None;
+        }
+        let mut listed = names[..names.len().min(max)].to_vec();
+        if names.len() > max {
+            listed.push(et_al.to_string());
+        }
+        Some(listed.join(", "))
+    }
+
+    /// Returns the year of publication.
+    ///
+    /// The year is taken from the first available date in order of precedence:
+    /// `published`, then `published_other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataDate};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// assert_eq!(metadata.year(), None);
+    /// metadata.published_other = Some(DoiMetadataDate {
+    ///     year: Some(2024),
+    ///     month: Some(2),
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(metadata.year(), Some(2024));
+    /// metadata.published = Some(DoiMetadataDate { year: Some(2023), ..Default::default() });
+    /// assert_eq!(metadata.year(), Some(2023));
+    /// ```
+    pub fn year(&self) -> Option<u32> {
+        [&self.published, &self.published_other]
+            .into_iter()
+            .find_map(|date| date.as_ref()?.year)
+    }
+
+    /// Returns the best URL to show a user for the document.
+    ///
+    /// The URL is chosen in the following order:
+    /// 1. if the document is open access (see [`Self::is_open_access`]),
+    ///    a full-text PDF link (see [`Self::links`]), preferring the version of record;
+    /// 2. if the document is open access, any other full-text link, preferring the version of record;
+    /// 3. the registered URL (see [`Self::url`]), unless it is a DOI URL itself;
+    /// 4. the DOI URL (`https://doi.org/<DOI_NUMBER>`), which redirects to the landing page.
+    ///
+    /// The full-text links of other documents (e.g., for text mining) are usually paywalled,
+    /// so the landing page is preferred for them.
+    /// Links intended for similarity checking are always skipped,
+    /// since they are meant for the Crossref service and usually not publicly accessible.
+    ///
+    /// Returns `None` if no link is chosen and the DOI is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataLicense, DoiMetadataLink};
+    /// let mut metadata = DoiMetadata::new("10.1/x".to_string());
+    /// assert_eq!(metadata.primary_url(), Some("https://doi.org/10.1/x".to_string()));
+    /// metadata.url = Some("https://example.org/x".to_string());
+    /// assert_eq!(metadata.primary_url(), Some("https://example.org/x".to_string()));
+    /// metadata.links = Some(vec![
+    ///     DoiMetadataLink {
+    ///         url: "https://example.org/x.xml".to_string(),
+    ///         content_type: Some("text/xml".to_string()),
+    ///         ..Default::default()
+    ///     },
+    ///     DoiMetadataLink {
+    ///         url: "https://example.org/x.pdf".to_string(),
+    ///         content_type: Some("application/pdf".to_string()),
+    ///         ..Default::default()
+    ///     },
+    /// ]);
+    /// // Not open access, so the full-text links are not preferred.
+    /// assert_eq!(metadata.primary_url(), Some("https://example.org/x".to_string()));
+    /// metadata.license = Some(vec![DoiMetadataLicense {
+    ///     url: "https://creativecommons.org/licenses/by/4.0/".to_string(),
+    ///     ..Default::default()
+    /// }]);
+    /// assert_eq!(metadata.primary_url(), Some("https://example.org/x.pdf".to_string()));
+    /// assert_eq!(DoiMetadata::default().primary_url(), None);
+    /// ```
+    pub fn primary_url(&self) -> Option<String> {
+        let is = |value: &Option<String>, expected: &str| {
+            value
+                .as_deref()
+                .is_some_and(|v| v.eq_ignore_ascii_case(expected))
+        };
+        self.links
+            .iter()
+            .flatten()
+            .filter(|_| self.is_open_access())
+            .filter(|link| !link.url.is_empty())
+            .filter(|link| !is(&link.intended_application, "similarity-checking"))
+            .min_by_key(|link| {
+                (
+                    !is(&link.content_type, "application/pdf"),
+                    !is(&link.content_version, "vor"),
+                )
+            })
+            .map(|link| link.url.clone())
+            .or_else(|| {
+                self.url.clone().filter(|url| {
+                    let host = url::Url::parse(url).ok();
+                    let host = host.as_ref().and_then(url::Url::host_str);
+                    !matches!(host, Some("doi.org" | "dx.doi.org" | "www.doi.org"))
+                })
+            })
+            .or_else(|| (!self.doi.is_empty()).then(|| format!("https://doi.org/{}", self.doi)))
+    }
+
+    /// Returns whether the document is likely open access.
+    ///
+    /// This is a heuristic checking for a Creative Commons license (or public domain dedication)
+    /// among [`Self::license`], regardless of its start date and content version.
+    /// Open-access documents under other licenses are not recognized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataLicense};
+    /// let mut metadata = DoiMetadata::default();
+    /// assert!(!metadata.is_open_access());
+    /// metadata.license = Some(vec![DoiMetadataLicense {
+    ///     url: "http://creativecommons.org/licenses/by/4.0/".to_string(),
+    ///     ..Default::default()
+    /// }]);
+    /// assert!(metadata.is_open_access());
+    /// ```
+    pub fn is_open_access(&self) -> bool {
+        self.license.iter().flatten().any(|license| {
+            let url = license.url.to_ascii_lowercase();
+            let url = url
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_start_matches("www.");
+            url.starts_with("creativecommons.org/licenses/")
+                || url.starts_with("creativecommons.org/publicdomain/")
+        })
+    }
+
+    /// Returns whether the document has the subject (see [`Self::subjects`]), ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadata;
+    /// let mut metadata = DoiMetadata::default();
+    /// assert!(!metadata.has_subject("Hardware and Architecture"));
+    /// metadata.subjects = Some(vec!["Hardware and Architecture".to_string()]);
+    /// assert!(metadata.has_subject("hardware and architecture"));
+    /// ```
+    pub fn has_subject(&self, subject: &str) -> bool {
+        let subject = subject.trim().to_lowercase();
+        self.subjects
+            .iter()
+            .flatten()
+            .any(|s| s.to_lowercase() == subject)
+    }
+
+    /// Returns the DOIs of the references (see [`Self::references`]) that have one.
+    ///
+    /// The DOIs are in the order of the references; references without a DOI are skipped.
+    /// (To get only these DOIs without the other metadata, see [`Doi::references`].)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataReference};
+    /// let mut metadata = DoiMetadata::default();
+    /// metadata.references = Some(vec![
+    ///     DoiMetadataReference {
+    ///         doi: Some("10.1109/TCAD.2020.3012345".to_string()),
+    ///         ..Default::default()
+    ///     },
+    ///     DoiMetadataReference {
+    ///         unstructured: Some("J. Doe, An Unpublished Note, 2020.".to_string()),
+    ///         ..Default::default()
+    ///     },
+    /// ]);
+    /// let dois = metadata.referenced_dois();
+    /// assert_eq!(dois.len(), 1);
+    /// assert_eq!(dois[0].to_string(), "10.1109/TCAD.2020.3012345");
+    /// ```
+    pub fn referenced_dois(&self) -> Vec<Doi> {
+        self.references
+            .iter()
+            .flatten()
+            .filter_map(|reference| reference.doi.as_deref())
+            .map(Doi::new)
+            .collect()
+    }
+
+    /// Returns the name of the venue where the document was published.
+    ///
+    /// For a conference paper, this is the event name (falling back to the container title,
+    /// usually the proceedings); otherwise, this is the container title (e.g., the journal)
+    /// falling back to the event name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, DoiMetadataType};
+    /// let mut article = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// article.r#type = Some(DoiMetadataType::ArticleJournal);
+    /// article.container_title = Some("IEEE Transactions on Circuits and Systems II: Express Briefs".to_string());
+    /// assert_eq!(article.venue(), Some("IEEE Transactions on Circuits and Systems II: Express Briefs"));
+    ///
+    /// let mut paper = DoiMetadata::new("10.1145/3643832.3661865".to_string());
+    /// paper.r#type = Some(DoiMetadataType::PaperConference);
+    /// paper.container_title = Some("Proceedings of the 22nd Annual International Conference on Mobile Systems, Applications and Services".to_string());
+    /// paper.event = Some("MOBISYS '24".to_string());
+    /// assert_eq!(paper.venue(), Some("MOBISYS '24"));
+    ///
+    /// let unknown = DoiMetadata::new("10.1/x".to_string());
+    /// assert_eq!(unknown.venue(), None);
+    /// ```
+    pub fn venue(&self) -> Option<&str> {
+        let container_title = self.container_title.as_deref();
+        let event = self.event.as_deref();
+        match self.r#type {
+            Some(DoiMetadataType::PaperConference) => event.or(container_title),
+            _ => container_title.or(event),
+        }
+    }
+
+    /// Returns the first and last page of the page range, e.g., `("123", "145")` for `123-145`.
+    ///
+    /// The range is split at the first hyphen, so prefixed pages like `S1-S5` are kept intact.
+    /// Returns `None` for a single page or an article number (e.g., `e12345`),
+    /// or if there are no pages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadata;
+    /// let mut metadata = DoiMetadata::default();
+    /// metadata.page = Some("3303-3307".to_string());
+    /// assert_eq!(metadata.page_range(), Some(("3303".to_string(), "3307".to_string())));
+    /// metadata.page = Some("S1-S5".to_string());
+    /// assert_eq!(metadata.page_range(), Some(("S1".to_string(), "S5".to_string())));
+    /// metadata.page = Some("e12345".to_string());
+    /// assert_eq!(metadata.page_range(), None);
+    /// ```
+    pub fn page_range(&self) -> Option<(String, String)> {
+        let (start, end) = self.page.as_deref()?.split_once('-')?;
+        let (start, end) = (start.trim(), end.trim());
+        (!start.is_empty() && !end.is_empty()).then(|| (start.to_string(), end.to_string()))
+    }
+
+    /// Returns the first ISSN of the container (usually the print ISSN), if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadata;
+    /// let mut metadata = DoiMetadata::default();
+    /// assert_eq!(metadata.primary_issn(), None);
+    /// metadata.issn = Some(vec!["1549-7747".to_string(), "1558-3791".to_string()]);
+    /// assert_eq!(metadata.primary_issn(), Some("1549-7747"));
+    /// ```
+    pub fn primary_issn(&self) -> Option<&str> {
+        self.issn.as_ref()?.first().map(String::as_str)
+    }
+
+    /// Returns the abstract as plain text, with the JATS (or HTML) markup removed.
+    ///
+    /// Tags are stripped and entities (e.g., `&amp;` and `&#233;`) are decoded.
+    /// Paragraphs are separated by a blank line, whitespace within them is collapsed,
+    /// and a leading "Abstract" heading is dropped.
+    /// Returns `None` if there is no abstract or it has no text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::DoiMetadata;
+    /// let mut metadata = DoiMetadata::default();
+    /// metadata.r#abstract = Some(
+    ///     "<jats:title>Abstract</jats:title><jats:p>Fast <jats:italic>and</jats:italic>\n \
+    ///      small &amp; simple.</jats:p><jats:p>Second.</jats:p>"
+    ///         .to_string(),
+    /// );
+    /// assert_eq!(
+    ///     metadata.abstract_text().as_deref(),
+    ///     Some("Fast and small & simple.\n\nSecond.")
+    /// );
+    /// assert_eq!(DoiMetadata::default().abstract_text(), None);
+    /// ```
+    pub fn abstract_text(&self) -> Option<String> {
+        let text = strip_markup(self.r#abstract.as_deref()?);
+        let mut paragraphs = text
+            .split('\n')
+            .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|paragraph| !paragraph.is_empty())
+            .peekable();
+        paragraphs.next_if(|paragraph| paragraph.eq_ignore_ascii_case("abstract"));
+        let text = paragraphs.collect::<Vec<_>>().join("\n\n");
+        (!text.is_empty()).then_some(text)
+    }
+}
+
+/// JSON parsing of the metadata.
+///
+/// Field shapes differ between schema versions and registrants
+/// (e.g., `container-title` is an array in Crossref but a string in CSL-JSON),
+/// so all fields are extracted through the shape-tolerant helpers below.
+impl DoiMetadata {
+    /// Extracts the metadata from JSON metadata (as returned by [`Doi::metadata_json`]).
+    ///
+    /// This is the extraction used by [`Doi::metadata`], e.g., for JSON responses saved earlier.
+    /// The DOI is taken from the `DOI` field (empty if absent).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, JsonValue};
+    /// let json: JsonValue = ureq::serde_json::from_str(r#"{
+    ///     "DOI": "10.1109/TCSII.2024.3366282",
+    ///     "title": ["Flexible High-Level Synthesis Library for Linear Transformations"]
+    /// }"#).unwrap();
+    /// let metadata = DoiMetadata::from_json(&json);
+    /// assert_eq!(metadata.doi, "10.1109/TCSII.2024.3366282");
+    /// assert!(metadata.title.is_some());
+    /// ```
+    pub fn from_json(json: &JsonValue) -> Self {
+        let mut metadata = Self::new(first_or_scalar(&json["DOI"]).unwrap_or_default());
+        let title = match &json["title"] {
+            JsonValue::Null => title(&json["titles"]),
+            value => title(value),
+        };
+        if let Some((title, language)) = title {
+            metadata.title = Some(title);
+            metadata.title_language = language;
+        }
+        // Some malformed records have a single author object instead of an array.
+        let authors = match &json["author"] {
+            JsonValue::Array(authors) => Some(authors.iter().collect::<Vec<_>>()),
+            author @ JsonValue::Object(_) => Some(vec![author]),
+            _ => None,
+        };
+        if let Some(authors) = authors {
+            let mut author_list = Vec::new();
+            for author in authors {
+                let given = non_empty_str(&author["given"]);
+                let family = non_empty_str(&author["family"]);
+                let suffix = non_empty_str(&author["suffix"]);
+                let literal =
+                    non_empty_str(&author["literal"]).or_else(|| non_empty_str(&author["name"]));
+                author_list.push(DoiMetadataPerson {
+                    given,
+                    family,
+                    suffix,
+                    literal,
+                    sequence: first_or_scalar(&author["sequence"]),
+                    affiliation: affiliation(&author["affiliation"]),
+                });
+            }
+            metadata.authors = Some(author_list);
+        }
+        if let Some(r#type) = json["type"].as_str() {
+            metadata.r#type = Some(DoiMetadataType::new(r#type));
+        }
+        metadata.container_doi = container_doi(json);
+        metadata.container_title = first_or_scalar(&json["container-title"]);
+        metadata.short_container_title = first_or_scalar(&json["short-container-title"]);
+        metadata.volume = string_or_number(&json["volume"]);
+        metadata.issue = string_or_number(&json["issue"]);
+        metadata.page = string_or_number(&json["page"]);
+        metadata.publisher = first_or_scalar(&json["publisher"]);
+        metadata.issn = issn(json);
+        metadata.isbn = isbn(&json["ISBN"]);
+        metadata.event = first_or_scalar(&json["event"])
+            .or_else(|| first_or_scalar(&json["event"]["name"]))
+            .or_else(|| first_or_scalar(&json["event-title"]));
+        metadata.subtype = first_or_scalar(&json["subtype"]);
+        metadata.clinical_trials = clinical_trials(json);
+        metadata.published = date(&json["issued"]).or_else(|| date(&json["published"]));
+        metadata.published_other = date(&json["published-other"]);
+        metadata.created = date(&json["created"]);
+        metadata.indexed = date(&json["indexed"]);
+        metadata.number_of_pages = number(&json["number-of-pages"]);
+        metadata.group_title = first_or_scalar(&json["group-title"]);
+        metadata.subjects = subjects(&json["subject"]);
+        metadata.language = non_empty_str(&json["language"]);
+        metadata.url = first_or_scalar(&json["URL"]);
+        metadata.links = links(json);
+        metadata.license = license(json);
+        metadata.references = references(json);
+        metadata.referenced_by_count = number(&json["is-referenced-by-count"]);
+        metadata.abstracts = abstracts(json);
+        metadata.r#abstract = first_or_scalar(&json["abstract"]).or_else(|| {
+            let (_, text) = metadata.abstracts.as_ref()?.first()?;
+            Some(text.clone())
+        });
+        metadata
+    }
+
+    /// Fills the fields that are not set yet from JSON metadata (e.g., of another source).
+    ///
+    /// The JSON is extracted as by [`Doi::metadata`], but only the fields that are `None`
+    /// (and the DOI if it is empty) are set; fields that are already set are kept as they are.
+    /// The title language is taken along with the title.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiMetadata, JsonValue};
+    /// let mut metadata = DoiMetadata::new("10.1109/TCSII.2024.3366282".to_string());
+    /// metadata.title = Some("Flexible HLS Library".to_string());
+    /// let json: JsonValue = ureq::serde_json::from_str(r#"{
+    ///     "title": "Another Title",
+    ///     "publisher": "Institute of Electrical and Electronics Engineers (IEEE)"
+    /// }"#).unwrap();
+    /// metadata.merge_from_json(&json);
+    /// assert_eq!(metadata.title.as_deref(), Some("Flexible HLS Library"));
+    /// assert!(metadata.publisher.is_some());
+    /// ```
+    pub fn merge_from_json(&mut self, json: &JsonValue) {
+        // Destructured so that a new field cannot be forgotten here.
+        let DoiMetadata {
+            doi,
+            title,
+            title_language,
+            authors,
+            r#type,
+            container_doi,
+            container_title,
+            short_container_title,
+            volume,
+            issue,
+            page,
+            publisher,
+            issn,
+            isbn,
+            event,
+            subtype,
+            clinical_trials,
+            published,
+            published_other,
+            created,
+            indexed,
+            number_of_pages,
+            group_title,
+            subjects,
+            language,
+            url,
+            links,
+            license,
+            references,
+            referenced_by_count,
+            r#abstract,
+            abstracts,
+        } = Self::from_json(json);
+        if self.doi.is_empty() {
+            self.doi = doi;
+        }
+        if self.title.is_none() {
+            self.title = title;
+            self.title_language = title_language;
+        }
+        fill(&mut self.authors, authors);
+        fill(&mut self.r#type, r#type);
+        fill(&mut self.container_doi, container_doi);
+        fill(&mut self.container_title, container_title);
+        fill(&mut self.short_container_title, short_container_title);
+        fill(&mut self.volume, volume);
+        fill(&mut self.issue, issue);
+        fill(&mut self.page, page);
+        fill(&mut self.publisher, publisher);
+        fill(&mut self.issn, issn);
+        fill(&mut self.isbn, isbn);
+        fill(&mut self.event, event);
+        fill(&mut self.subtype, subtype);
+        fill(&mut self.clinical_trials, clinical_trials);
+        fill(&mut self.published, published);
+        fill(&mut self.published_other, published_other);
+        fill(&mut self.created, created);
+        fill(&mut self.indexed, indexed);
+        fill(&mut self.number_of_pages, number_of_pages);
+        fill(&mut self.group_title, group_title);
+        fill(&mut self.subjects, subjects);
+        fill(&mut self.language, language);
+        fill(&mut self.url, url);
+        fill(&mut self.links, links);
+        fill(&mut self.license, license);
+        fill(&mut self.references, references);
+        fill(&mut self.referenced_by_count, referenced_by_count);
+        fill(&mut self.r#abstract, r#abstract);
+        fill(&mut self.abstracts, abstracts);
+    }
+
+    /// Extracts the metadata like [`Self::from_json`], also returning warnings
+    /// about fields that are present but cannot be parsed (and are thus dropped).
+    pub(crate) fn from_json_with_warnings(json: &JsonValue) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut check = |field: &str, valid: fn(&JsonValue) -> bool| {
+            let value = &json[field];
+            if !value.is_null() && !valid(value) {
+                warnings.push(format!("Unparseable `{}` field: {}", field, value));
+            }
+        };
+        check("title", |v| first_or_scalar(v).is_some());
+        check("author", |v| v.is_array() || v.is_object());
+        check("type", JsonValue::is_string);
+        for field in DATE_FIELDS {
+            check(field, is_complete_date);
+        }
+        check("number-of-pages", |v| number::<u32>(v).is_some());
+        (Self::from_json(json), warnings)
+    }
+}
+
+/// Sets `field` to `value` if it is not set yet.
+fn fill<T>(field: &mut Option<T>, value: Option<T>) {
+    if field.is_none() {
+        *field = value;
+    }
+}
+
+/// JSON fields holding a CSL date object.
+const DATE_FIELDS: [&str; 5] = [
+    "issued",
+    "published",
+    "published-other",
+    "created",
+    "indexed",
+];
+
+/// Returns the string of a JSON value that is either a string or an array of strings (first).
+fn first_or_scalar(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Array(values) => values.iter().find_map(|v| v.as_str()),
+        value => value.as_str(),
+    }
+    .map(|s| s.to_string())
+}
+
+/// Extracts a non-empty string from a JSON value that is either a string or a number
+/// (e.g., a volume deposited as `71` rather than `"71"`).
+fn string_or_number(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Number(number) => Some(number.to_string()),
+        value => non_empty_str(value),
+    }
+}
+
+/// Extracts a string (or the first of an array), trimmed and dropped if blank.
+fn non_empty_str(value: &JsonValue) -> Option<String> {
+    first_or_scalar(value)
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Extracts the title and its language tag from a JSON value that is either a string
+/// or an array of strings or DataCite title objects (`{"title": ..., "lang": ...}`).
+///
+/// Titles are often deposited with stray line breaks or doubled spaces,
+/// so the whitespace is trimmed and collapsed.
+/// Of an array (which may repeat the same entry), the first non-empty title is used.
+/// DataCite subtitles and translated titles (entries with a `titleType`) are skipped.
+fn title(value: &JsonValue) -> Option<(String, Option<String>)> {
+    let titles = match value {
+        JsonValue::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    titles
+        .into_iter()
+        .filter_map(|title| match title {
+            JsonValue::String(title) => Some((title.as_str(), None)),
+            JsonValue::Object(_) if title["titleType"].is_null() => Some((
+                title["title"].as_str()?,
+                non_empty_str(&title["lang"]).or_else(|| non_empty_str(&title["xml:lang"])),
+            )),
+            _ => None,
+        })
+        .map(|(title, lang)| (title.split_whitespace().collect::<Vec<_>>().join(" "), lang))
+        .find(|(title, _)| !title.is_empty())
+}
+
+/// Returns the number of a JSON value that is either a number or a string-encoded number,
+/// or `None` if it does not fit in `T`.
+fn number<T: FromStr + TryFrom<u64>>(value: &JsonValue) -> Option<T> {
+    match value {
+        JsonValue::String(s) => s.trim().parse().ok(),
+        value => value.as_u64().and_then(|n| T::try_from(n).ok()),
+    }
+}
+
+/// Extracts the date from a CSL date object.
+///
+/// The `date-parts` array is preferred. Some sources instead give a `raw` string,
+/// a Crossref `date-time` timestamp, or just an ISO date string (e.g., `"2024-02-14"`),
+/// which are parsed as `YYYY[-MM[-DD]]` (see [`parse_date`]).
+fn date(value: &JsonValue) -> Option<DoiMetadataDate> {
+    date_parts(value)
+        .or_else(|| parse_date(&first_or_scalar(&value["raw"])?))
+        .or_else(|| parse_date(value["date-time"].as_str()?))
+        .or_else(|| parse_date(value.as_str()?))
+}
+
+/// Extracts the date from a CSL date object with a `date-parts` array.
+///
+/// Only the first date is used (a range has two), and its parts may be numbers or strings.
+/// Returns `None` if there is no year.
+fn date_parts(value: &JsonValue) -> Option<DoiMetadataDate> {
+    let parts = value["date-parts"][0].as_array()?;
+    let part = |i: usize| number(parts.get(i)?);
+    Some(DoiMetadataDate {
+        year: Some(part(0)?),
+        month: part(1),
+        day: part(2),
+    })
+}
+
+/// Parses a date of the form `YYYY[-MM[-DD]]` (also with `/` as the separator).
+///
+/// A time after the date (e.g., `2024-02-14T10:00:00Z`) is ignored.
+/// Returns `None` if there is no year.
+pub(crate) fn parse_date(date: &str) -> Option<DoiMetadataDate> {
+    let date = date.trim().split(['T', ' ']).next()?;
+    let mut parts = date.split(['-', '/']).map(|part| part.parse().ok());
+    Some(DoiMetadataDate {
+        year: Some(parts.next()??),
+        month: parts.next().flatten(),
+        day: parts.next().flatten(),
+    })
+}
+
+/// Checks if a CSL date object has a year and all its date parts are numbers.
+///
+/// Without a `date-parts` array, the date must be parseable from the other forms.
+fn is_complete_date(value: &JsonValue) -> bool {
+    match value["date-parts"][0].as_array() {
+        Some(parts) => {
+            date_parts(value).is_some() && parts.iter().all(|part| number::<u32>(part).is_some())
+        }
+        None => date(value).is_some(),
+    }
+}
+
+/// Extracts the DOI of a newer version from the JSON metadata.
+///
+/// Crossref lists newer versions as `is-replaced-by` or `is-previous-version-of` relations,
+/// or as `updated-by` entries of the `new_version` or `new_edition` type.
+/// (The `update-to` field of the newer record points back to the older one.)
+fn newer_version(json: &JsonValue) -> Option<String> {
+    for relation_type in ["is-replaced-by", "is-previous-version-of"] {
+        for relation in json["relation"][relation_type]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            if relation["id-type"].as_str() == Some("doi") {
+                if let Some(id) = relation["id"].as_str() {
+                    return Some(id.to_string());
+                }
+            }
+        }
+    }
+    json["updated-by"]
+        .as_array()?
+        .iter()
+        .filter(|update| matches!(update["type"].as_str(), Some("new_version" | "new_edition")))
+        .find_map(|update| first_or_scalar(&update["DOI"]))
+}
+
+/// Extracts the container DOI from the JSON metadata.
+///
+/// Crossref lists it as an `is-part-of` relation with a `doi` ID type,
+/// while DataCite uses a `container` object with a `DOI` identifier type.
+fn container_doi(json: &JsonValue) -> Option<String> {
+    if let Some(relations) = json["relation"]["is-part-of"].as_array() {
+        for relation in relations {
+            if relation["id-type"].as_str() == Some("doi") {
+                if let Some(id) = relation["id"].as_str() {
+                    return Some(id.to_string());
+                }
+            }
+        }
+    }
+    let container = &json["container"];
+    if container["identifierType"]
+        .as_str()
+        .is_some_and(|t| t.eq_ignore_ascii_case("doi"))
+    {
+        return first_or_scalar(&container["identifier"]);
+    }
+    None
+}
+
+/// Extracts the affiliation names of an author.
+///
+/// Crossref lists them as objects with a `name`, while DataCite may also use plain strings.
+fn affiliation(value: &JsonValue) -> Option<Vec<String>> {
+    let names: Vec<_> = value
+        .as_array()?
+        .iter()
+        .filter_map(|affiliation| {
+            first_or_scalar(affiliation).or_else(|| first_or_scalar(&affiliation["name"]))
+        })
+        .collect();
+    (!names.is_empty()).then_some(names)
+}
+
+/// Extracts the ISSNs from the `ISSN` array of the JSON metadata,
+/// falling back to the values of the `issn-type` entries.
+fn issn(json: &JsonValue) -> Option<Vec<String>> {
+    let issns: Vec<_> = match &json["ISSN"] {
+        JsonValue::Array(values) => values.iter().filter_map(non_empty_str).collect(),
+        value => non_empty_str(value).into_iter().collect(),
+    };
+    if !issns.is_empty() {
+        return Some(issns);
+    }
+    let issns: Vec<_> = json["issn-type"]
+        .as_array()?
+        .iter()
+        .filter_map(|entry| non_empty_str(&entry["value"]))
+        .collect();
+    (!issns.is_empty()).then_some(issns)
+}
+
+/// Extracts the ISBNs from the `ISBN` array of the JSON metadata,
+/// stripping the `http://id.crossref.org/isbn/` URL prefix that Crossref sometimes uses.
+fn isbn(value: &JsonValue) -> Option<Vec<String>> {
+    let values = match value {
+        JsonValue::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    let isbns: Vec<_> = values
+        .into_iter()
+        .filter_map(JsonValue::as_str)
+        .map(|isbn| {
+            let isbn = isbn.trim();
+            isbn.strip_prefix("http://id.crossref.org/isbn/")
+                .or_else(|| isbn.strip_prefix("https://id.crossref.org/isbn/"))
+                .unwrap_or(isbn)
+                .to_string()
+        })
+        .filter(|isbn| !isbn.is_empty())
+        .collect();
+    (!isbns.is_empty()).then_some(isbns)
+}
+
+/// Extracts the full-text links from the `link` array of the JSON metadata.
+///
+/// Entries without a URL are skipped.
+fn links(json: &JsonValue) -> Option<Vec<DoiMetadataLink>> {
+    let links = json["link"].as_array()?;
+    Some(
+        links
+            .iter()
+            .filter_map(|link| {
+                Some(DoiMetadataLink {
+                    url: first_or_scalar(&link["URL"])?,
+                    content_type: first_or_scalar(&link["content-type"]),
+                    content_version: first_or_scalar(&link["content-version"]),
+                    intended_application: first_or_scalar(&link["intended-application"]),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Extracts the subjects from the `subject` array of the JSON metadata,
+/// keeping the first of exact repeats.
+fn subjects(value: &JsonValue) -> Option<Vec<String>> {
+    let values = match value {
+        JsonValue::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    let mut subjects: Vec<String> = Vec::new();
+    for subject in values.into_iter().filter_map(non_empty_str) {
+        if !subjects.contains(&subject) {
+            subjects.push(subject);
+        }
+    }
+    (!subjects.is_empty()).then_some(subjects)
+}
+
+/// Extracts the licenses from the `license` array of the JSON metadata.
+///
+/// Entries without a URL are skipped.
+fn license(json: &JsonValue) -> Option<Vec<DoiMetadataLicense>> {
+    let licenses = json["license"].as_array()?;
+    Some(
+        licenses
+            .iter()
+            .filter_map(|license| {
+                Some(DoiMetadataLicense {
+                    url: first_or_scalar(&license["URL"])?,
+                    content_version: first_or_scalar(&license["content-version"]),
+                    start: date(&license["start"]),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Extracts the references from the `reference` array of the JSON metadata.
+fn references(json: &JsonValue) -> Option<Vec<DoiMetadataReference>> {
+    let references = json["reference"].as_array()?;
+    Some(
+        references
+            .iter()
+            .map(|reference| DoiMetadataReference {
+                doi: non_empty_str(&reference["DOI"]),
+                key: non_empty_str(&reference["key"]),
+                unstructured: non_empty_str(&reference["unstructured"]),
+            })
+            .collect(),
+    )
+}
+
+/// Extracts the abstracts with their language tags from the JSON metadata.
+///
+/// DataCite lists them in the `descriptions` array, where only the entries with the `Abstract`
+/// description type (or none) are used; otherwise, the `abstract` field is used without a language.
+fn abstracts(json: &JsonValue) -> Option<Vec<(Option<String>, String)>> {
+    let abstracts: Vec<_> = json["descriptions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(
+            |description| match description["descriptionType"].as_str() {
+                Some(r#type) => r#type.eq_ignore_ascii_case("abstract"),
+                None => true,
+            },
+        )
+        .filter_map(|description| {
+            let text = non_empty_str(&description["description"])?;
+            let lang = non_empty_str(&description["lang"])
+                .or_else(|| non_empty_str(&description["xml:lang"]));
+            Some((lang, text))
+        })
+        .collect();
+    if !abstracts.is_empty() {
+        return Some(abstracts);
+    }
+    Some(vec![(None, first_or_scalar(&json["abstract"])?)])
+}
+
+/// Strips the tags of JATS (or HTML) markup and decodes the entities.
+///
+/// Block-level tags (e.g., `<jats:p>`) become line breaks,
+/// while inline tags (e.g., `<jats:italic>`) are removed.
+fn strip_markup(markup: &str) -> String {
+    const BLOCKS: [&str; 8] = [
+        "p",
+        "title",
+        "sec",
+        "list",
+        "list-item",
+        "br",
+        "div",
+        "abstract",
+    ];
+    // Line breaks in the markup are mere whitespace, leaving them to separate the blocks.
+    let markup = markup.replace(['\r', '\n'], " ");
+    let mut text = String::with_capacity(markup.len());
+    let mut rest = markup.as_str();
+    while let Some(start) = rest.find(['<', '&']) {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with('<') {
+            let Some(end) = rest.find('>') else {
+                break;
+            };
+            let name = rest[1..end]
+                .trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default();
+            let name = name.rsplit(':').next().unwrap_or_default();
+            if BLOCKS.iter().any(|block| block.eq_ignore_ascii_case(name)) {
+                text.push('\n');
+            }
+            rest = &rest[end + 1..];
+        } else {
+            let entity = rest
+                .find(';')
+                .filter(|&end| end <= 10)
+                .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+            match entity {
+                Some((c, end)) => {
+                    text.push(c);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    text.push('&');
+                    rest = &rest[1..];
+                }
+            }
         }
     }
+    text.push_str(rest);
+    text
+}
+
+/// Decodes an entity name (e.g., `amp` or `#233`) without the `&` and `;`.
+fn decode_entity(name: &str) -> Option<char> {
+    let code = match name {
+        "amp" => return Some('&'),
+        "lt" => return Some('<'),
+        "gt" => return Some('>'),
+        "quot" => return Some('"'),
+        "apos" => return Some('\''),
+        "nbsp" => return Some(' '),
+        _ => name.strip_prefix('#')?,
+    };
+    let code = match code.strip_prefix(['x', 'X']) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => code.parse().ok()?,
+    };
+    char::from_u32(code)
+}
+
+/// Extracts the clinical trial numbers from the JSON metadata.
+///
+/// Crossref lists them in the `clinical-trial-number` array,
+/// where the registry is identified by its DOI (e.g., `10.18810/clinical-trials-gov`).
+/// Entries without a number are skipped, and a missing registry is left empty.
+fn clinical_trials(json: &JsonValue) -> Option<Vec<(String, String)>> {
+    let trials = json["clinical-trial-number"].as_array()?;
+    Some(
+        trials
+            .iter()
+            .filter_map(|trial| {
+                let number = first_or_scalar(&trial["clinical-trial-number"])?;
+                let registry = first_or_scalar(&trial["registry"]).unwrap_or_default();
+                Some((registry, number))
+            })
+            .collect(),
+    )
 }
 
 impl Doi {
@@ -247,8 +1637,8 @@ impl Doi {
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the DOI is not set, i.e., `None`.
-    /// Returns a `Box<dyn Error>` if there is an error fetching metadata from doi.org.
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
     ///
     /// # Example
     ///
@@ -269,47 +1659,279 @@ impl Doi {
     ///     Err(e) => eprintln!("Error: {}", e),
     /// }
     /// ```
-    pub fn metadata(&self) -> Result<DoiMetadata, Box<dyn Error>> {
+    ///
+    /// The container DOI is available when the registrant deposited one:
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.5194/essd-16-2033-2024");
+    /// match doi.metadata() {
+    ///     Ok(metadata) => {
+    ///         if let Some(container_doi) = metadata.container_doi {
+    ///             println!("Container DOI: {}", container_doi);
+    ///             assert!(container_doi.starts_with("10."));
+    ///         }
+    ///     },
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn metadata(&self) -> Result<DoiMetadata, DoiError> {
         let doi = self.get_doi()?;
-        let mut metadata = DoiMetadata::new(doi);
-        let json = self.metadata_json()?;
-        if let Some(title) = json["title"].as_str() {
-            metadata.title = Some(title.to_string());
-        }
-        if let Some(authors) = json["author"].as_array() {
-            let mut author_list = Vec::new();
-            for author in authors {
-                let given = author["given"].as_str().map(|s| s.to_string());
-                let family = author["family"].as_str().map(|s| s.to_string());
-                let suffix = author["suffix"].as_str().map(|s| s.to_string());
-                author_list.push(DoiMetadataPerson {
-                    given,
-                    family,
-                    suffix,
-                });
-            }
-            metadata.authors = Some(author_list);
+        let mut metadata = DoiMetadata::from_json(&self.metadata_json()?);
+        metadata.doi = doi;
+        Ok(metadata)
+    }
+
+    /// Fetches metadata for the DOI, also returning warnings about malformed fields.
+    ///
+    /// Fields that are present in the JSON metadata but cannot be parsed
+    /// (e.g., a date with a non-numeric part) are silently dropped by [`Self::metadata`].
+    /// This returns the same metadata with a human-readable warning for each of them,
+    /// which helps debugging data-quality issues of the registrant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.metadata_with_warnings() {
+    ///     Ok((metadata, warnings)) => {
+    ///         println!("Paper Title: {}", metadata.title.unwrap_or("<unknown>".to_string()));
+    ///         for warning in warnings {
+    ///             eprintln!("Warning: {}", warning);
+    ///         }
+    ///     },
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn metadata_with_warnings(&self) -> Result<(DoiMetadata, Vec<String>), DoiError> {
+        let doi = self.get_doi()?;
+        let (mut metadata, warnings) = DoiMetadata::from_json_with_warnings(&self.metadata_json()?);
+        metadata.doi = doi;
+        Ok((metadata, warnings))
+    }
+
+    /// Fetches metadata for the DOI on the first call and memoizes it on this instance.
+    ///
+    /// Later calls return the memoized metadata without any request,
+    /// until the DOI is changed (e.g., via [`Self::set_doi`]).
+    /// Errors are not memoized, so a failed call is retried by the next one.
+    ///
+    /// The metadata is kept behind a `Mutex`, so [`Doi`] stays `Send` and `Sync`
+    /// and the accessors only need `&self`.
+    /// Concurrent first calls wait for each other and issue a single request.
+    /// A clone of the [`Doi`] starts with the metadata memoized so far,
+    /// but memoizes independently afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// if let Ok(metadata) = doi.metadata_lazy() {
+    ///     println!("Paper Title: {}", metadata.title.as_deref().unwrap_or("<unknown>"));
+    /// }
+    /// ```
+    pub fn metadata_lazy(&self) -> Result<Arc<DoiMetadata>, DoiError> {
+        let doi = self.get_doi()?;
+        let mut memo = self
+            .lazy_metadata
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(metadata) = memo.as_ref().filter(|metadata| metadata.doi == doi) {
+            return Ok(Arc::clone(metadata));
         }
-        if let Some(r#type) = json["type"].as_str() {
-            metadata.r#type = Some(DoiMetadataType::new(r#type));
+        let metadata = Arc::new(self.metadata()?);
+        *memo = Some(Arc::clone(&metadata));
+        Ok(metadata)
+    }
+
+    /// Returns the title of the DOI, fetching metadata lazily (see [`Self::metadata_lazy`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// if let Ok(Some(title)) = doi.title_lazy() {
+    ///     println!("Paper Title: {}", title);
+    /// }
+    /// ```
+    pub fn title_lazy(&self) -> Result<Option<String>, DoiError> {
+        Ok(self.metadata_lazy()?.title.clone())
+    }
+
+    /// Returns the authors of the DOI, fetching metadata lazily (see [`Self::metadata_lazy`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
+    pub fn authors_lazy(&self) -> Result<Option<Vec<DoiMetadataPerson>>, DoiError> {
+        Ok(self.metadata_lazy()?.authors.clone())
+    }
+
+    /// Fetches metadata for the DOI in the preferred format.
+    ///
+    /// The format is set via [`DoiBuilder::prefer_format`](crate::DoiBuilder::prefer_format).
+    /// With the default [`MetadataFormat::Json`], this is the same as [`Self::metadata`]
+    /// (including the cache); other formats are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiBuilder, MetadataFormat};
+    /// let doi = DoiBuilder::new()
+    ///     .doi("10.1109/TCSII.2024.3366282")
+    ///     .prefer_format(MetadataFormat::CslJson)
+    ///     .build();
+    /// match doi.metadata_preferred() {
+    ///     Ok(metadata) => println!("Paper Title: {}", metadata.title.unwrap_or("<unknown>".to_string())),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn metadata_preferred(&self) -> Result<DoiMetadata, DoiError> {
+        if self.metadata_format == MetadataFormat::Json {
+            return self.metadata();
         }
+        let doi = self.get_doi()?;
+        let json =
+            ureq::serde_json::from_slice(&self.metadata_bytes(self.metadata_format.accept())?)
+                .map_err(|e| DoiError::Parse(format!("Error parsing JSON: {}", e)))?;
+        let mut metadata = DoiMetadata::from_json(&json);
+        metadata.doi = doi;
         Ok(metadata)
     }
 
+    /// Resolves the DOI and fetches its metadata.
+    ///
+    /// The metadata negotiation is redirected to the registration agency (e.g., Crossref)
+    /// rather than the landing page, so this sends two requests sharing the connection pool:
+    /// a HEAD request as in [`Self::resolve`] and a GET request as in [`Self::metadata`]
+    /// (each following its redirects).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error resolving the DOI or fetching its metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve_and_metadata() {
+    ///     Ok((link, metadata)) => {
+    ///         println!("Resolved link: {}", link);
+    ///         println!("Paper Title: {}", metadata.title.unwrap_or("<unknown>".to_string()));
+    ///     },
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn resolve_and_metadata(&self) -> Result<(String, DoiMetadata), DoiError> {
+        Ok((self.resolve()?, self.metadata()?))
+    }
+
+    /// Returns the latest version of the DOI by following newer-version relations.
+    ///
+    /// The metadata is checked for a newer version (see below) and followed
+    /// up to 5 times, stopping at a DOI without a newer version or a cycle.
+    /// The returned [`Doi`] shares the configuration of this one,
+    /// and is the same DOI if it is already the latest.
+    ///
+    /// A newer version is listed as an `is-replaced-by` or `is-previous-version-of` relation,
+    /// or an `updated-by` entry of the `new_version` or `new_edition` type (Crossref).
+    /// Other updates (e.g., corrections) are not followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching the metadata of any version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.latest() {
+    ///     Ok(latest) => println!("Latest version: {}", latest.doi.unwrap()),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn latest(&self) -> Result<Doi, DoiError> {
+        const MAX_VERSIONS: usize = 5;
+        let mut latest = self.clone();
+        let mut seen = vec![self.get_doi()?.to_lowercase()];
+        for _ in 0..MAX_VERSIONS {
+            let Some(newer) = newer_version(&latest.metadata_json()?) else {
+                break;
+            };
+            let newer = Doi::normalize_input(newer);
+            if seen.contains(&newer.to_lowercase()) {
+                break;
+            }
+            seen.push(newer.to_lowercase());
+            latest.doi = Some(newer);
+        }
+        Ok(latest)
+    }
+
     /// Fetches metadata for the DOI (with `.call()?`).
-    fn metadata_call(&self, accept: &str) -> Result<ureq::Response, Box<dyn Error>> {
+    fn metadata_call(&self, accept: &str) -> Result<ureq::Response, DoiError> {
         self.get_doi()?; // Check if DOI is set.
-        Ok(self
-            .agent
-            .get(&self.https_url())
-            .set("Accept", accept)
-            .call()?)
+        let url = self.https_url();
+        self.with_retries(|deadline| {
+            self.before_deadline(self.agent.get(&url), deadline)?
+                .set("Accept", accept)
+                .call()
+                .map_err(|e| DoiError::from_call(e, &url))
+        })
+    }
+
+    /// Fetches metadata for the DOI as raw bytes.
+    ///
+    /// Some publisher CDNs send gzip bodies with a missing or wrong `Content-Encoding`.
+    /// As a robustness workaround, a body starting with the gzip magic bytes (`1f 8b`)
+    /// is decompressed here, since neither JSON nor text can start with them.
+    pub(crate) fn metadata_bytes(&self, accept: &str) -> Result<Vec<u8>, DoiError> {
+        let mut body = Vec::new();
+        self.metadata_call(accept)?
+            .into_reader()
+            .read_to_end(&mut body)?;
+        if body.starts_with(&[0x1f, 0x8b]) {
+            let mut decoded = Vec::new();
+            GzDecoder::new(&body[..]).read_to_end(&mut decoded)?;
+            body = decoded;
+        }
+        Ok(body)
     }
 
     /// Fetches metadata for the DOI in JSON format.
     ///
     /// Serde JSON is used to parse the JSON response, which is returned as a `serde_json::Value`.
     /// This type is aliased as [`JsonValue`] in the `doi` crate.
+    /// With the `cache` feature, the result is taken from the `DoiCache` if enabled.
     ///
     /// # DOI API
     ///
@@ -321,8 +1943,8 @@ impl Doi {
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the DOI is not set, i.e., `None`.
-    /// Returns a `Box<dyn Error>` if there is an error fetching metadata from doi.org.
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
     ///
     /// # Example
     ///
@@ -338,18 +1960,30 @@ impl Doi {
     ///     Err(e) => eprintln!("Error: {}", e),
     /// }
     /// ```
-    pub fn metadata_json(&self) -> Result<JsonValue, Box<dyn Error>> {
-        self.metadata_call("application/json")?
-            .into_json()
-            .map_err(|e| format!("Error parsing JSON: {}", e).into())
+    pub fn metadata_json(&self) -> Result<JsonValue, DoiError> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            return cache.metadata_json.get_or_fetch(
+                &self.cache_key()?,
+                cache.negative_ttl(),
+                || self.metadata_json_uncached(),
+            );
+        }
+        self.metadata_json_uncached()
+    }
+
+    /// Fetches metadata for the DOI in JSON format without the cache.
+    fn metadata_json_uncached(&self) -> Result<JsonValue, DoiError> {
+        ureq::serde_json::from_slice(&self.metadata_bytes("application/json")?)
+            .map_err(|e| DoiError::Parse(format!("Error parsing JSON: {}", e)))
     }
 
     /// Fetches metadata for the DOI in JSON format (as a string).
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the DOI is not set, i.e., `None`.
-    /// Returns a `Box<dyn Error>` if there is an error fetching metadata from doi.org.
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
     ///
     /// # Example
     ///
@@ -361,14 +1995,15 @@ impl Doi {
     ///     Err(e) => eprintln!("Error: {}", e),
     /// }
     /// ```
-    pub fn metadata_json_string(&self) -> Result<String, Box<dyn Error>> {
-        self.metadata_call("application/json")?
-            .into_string()
-            .map_err(|e| format!("Error parsing JSON: {}", e).into())
+    pub fn metadata_json_string(&self) -> Result<String, DoiError> {
+        String::from_utf8(self.metadata_bytes("application/json")?)
+            .map_err(|e| DoiError::Parse(format!("Error parsing JSON: {}", e)))
     }
 
     /// Fetches metadata for the DOI in BibTeX format.
     ///
+    /// This requires the `formats` feature (enabled by default).
+    ///
     /// # DOI API
     ///
     /// Internally, this method calls the doi.org API with the `Accept: application/x-bibtex` header.
@@ -379,8 +2014,8 @@ impl Doi {
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the DOI is not set, i.e., `None`.
-    /// Returns a `Box<dyn Error>` if there is an error fetching metadata from doi.org.
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
     ///
     /// # Example
     ///
@@ -392,9 +2027,9 @@ impl Doi {
     ///     Err(e) => eprintln!("Error: {}", e),
     /// }
     /// ```
-    pub fn metadata_bibtex(&self) -> Result<String, Box<dyn Error>> {
-        self.metadata_call("application/x-bibtex")?
-            .into_string()
-            .map_err(|e| format!("Error fetching BibTeX: {}", e).into())
+    #[cfg(feature = "formats")]
+    pub fn metadata_bibtex(&self) -> Result<String, DoiError> {
+        String::from_utf8(self.metadata_bytes("application/x-bibtex")?)
+            .map_err(|e| DoiError::Parse(format!("Error fetching BibTeX: {}", e)))
     }
 }
@@ -0,0 +1,36 @@
+use crate::Doi;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Applies `f` to each DOI on a bounded pool of `threads` threads (at least one).
+///
+/// The DOIs are handed out one at a time, so a slow request does not hold up a whole batch.
+/// The results are returned in the order of `dois`.
+pub(crate) fn map<T, F>(dois: &[Doi], threads: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&Doi) -> T + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, dois.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(doi) = dois.get(i) else {
+                    break;
+                };
+                if sender.send((i, f(doi))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+    let mut results: Vec<_> = receiver.into_iter().collect();
+    results.sort_unstable_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
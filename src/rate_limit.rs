@@ -0,0 +1,54 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use ureq::{Middleware, MiddlewareNext, Request, Response};
+
+/// Token-bucket rate limiter shared by the agents of the [`Doi`](crate::Doi) instances
+/// built by the same [`DoiBuilder`](crate::DoiBuilder).
+///
+/// The bucket holds at most one token, so requests are evenly spaced
+/// by at least `1 / per_second` seconds.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    per_second: f64,
+    /// Available tokens (negative if requests are waiting) and the last refill time.
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] allowing `per_second` requests per second.
+    pub(crate) fn new(per_second: f64) -> Self {
+        Self {
+            per_second,
+            state: Mutex::new((1.0, Instant::now())),
+        }
+    }
+
+    /// Takes a token, blocking until one is available.
+    pub(crate) fn acquire(&self) {
+        let wait = {
+            let mut state = self.state.lock().unwrap();
+            let (tokens, last) = &mut *state;
+            let now = Instant::now();
+            *tokens =
+                (*tokens + now.duration_since(*last).as_secs_f64() * self.per_second).min(1.0);
+            *last = now;
+            // Reserve the token even if it is not there yet, so waiting callers queue up.
+            *tokens -= 1.0;
+            (*tokens < 0.0).then(|| Duration::from_secs_f64(-*tokens / self.per_second))
+        };
+        if let Some(wait) = wait {
+            thread::sleep(wait);
+        }
+    }
+}
+
+/// Middleware applying a shared [`RateLimiter`] to every request of an agent.
+pub(crate) struct RateLimit(pub(crate) Arc<RateLimiter>);
+
+impl Middleware for RateLimit {
+    fn handle(&self, request: Request, next: MiddlewareNext) -> Result<Response, ureq::Error> {
+        self.0.acquire();
+        next.handle(request)
+    }
+}
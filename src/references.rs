@@ -0,0 +1,164 @@
+use crate::{Doi, DoiError};
+use std::fmt;
+use ureq::serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use ureq::serde_json::Value as JsonValue;
+
+impl Doi {
+    /// Returns the DOIs referenced by the document, from the `reference` array of the JSON metadata.
+    ///
+    /// Highly-cited works may list thousands of references,
+    /// so instead of building the whole JSON tree (as [`Self::metadata_json`] does),
+    /// the response is deserialized in a single pass that only keeps the `DOI` of each reference.
+    /// References without a DOI (e.g., unstructured citations) are skipped,
+    /// as are malformed entries (e.g., `null`) of the array.
+    /// The DOIs are in the order of the references, and the cache is not used.
+    /// (The full references are in [`DoiMetadata::references`](crate::DoiMetadata::references).)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns [`DoiError::Parse`] if the response is not a JSON object.
+    /// Returns a [`DoiError`] if there is an error fetching metadata from doi.org.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.references() {
+    ///     Ok(references) => println!("{} referenced DOIs", references.len()),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn references(&self) -> Result<Vec<String>, DoiError> {
+        let References(dois) =
+            ureq::serde_json::from_slice(&self.metadata_bytes("application/json")?)
+                .map_err(|e| DoiError::Parse(format!("Error parsing JSON: {}", e)))?;
+        Ok(dois)
+    }
+}
+
+/// The DOIs in the `reference` array of the JSON metadata, skipping all other fields.
+struct References(Vec<String>);
+
+impl<'de> Deserialize<'de> for References {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(MetadataVisitor)
+    }
+}
+
+struct MetadataVisitor;
+
+impl<'de> Visitor<'de> for MetadataVisitor {
+    type Value = References;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON metadata object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut dois = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "reference" {
+                dois = map.next_value::<ReferenceList>()?.0;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(References(dois))
+    }
+}
+
+/// The DOIs of a `reference` array (empty if it is `null`).
+struct ReferenceList(Vec<String>);
+
+impl<'de> Deserialize<'de> for ReferenceList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ReferenceListVisitor)
+    }
+}
+
+struct ReferenceListVisitor;
+
+impl<'de> Visitor<'de> for ReferenceListVisitor {
+    type Value = ReferenceList;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of references")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(ReferenceList(Vec::new()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut dois = Vec::new();
+        while let Some(reference) = seq.next_element::<Reference>()? {
+            dois.extend(reference.0);
+        }
+        Ok(ReferenceList(dois))
+    }
+}
+
+/// The `DOI` of a reference (`None` if absent or not a string, or if the reference is not an object).
+struct Reference(Option<String>);
+
+impl<'de> Deserialize<'de> for Reference {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ReferenceVisitor)
+    }
+}
+
+struct ReferenceVisitor;
+
+impl<'de> Visitor<'de> for ReferenceVisitor {
+    type Value = Reference;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a reference object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut doi = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "DOI" {
+                doi = match map.next_value::<JsonValue>()? {
+                    JsonValue::String(value) if !value.trim().is_empty() => Some(value),
+                    _ => None,
+                };
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(Reference(doi))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(Reference(None))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Reference(None))
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(Reference(None))
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(Reference(None))
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(Reference(None))
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(Reference(None))
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(Reference(None))
+    }
+}
@@ -0,0 +1,712 @@
+use crate::{pool, Doi, DoiError};
+use percent_encoding::percent_decode_str;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+use ureq::Agent;
+
+/// Resolver that maps a DOI number to the URL requested for it.
+///
+/// The URL is used by [`Doi::https_url`] and hence by both resolution and metadata requests.
+#[derive(Clone, Default)]
+pub enum Resolver {
+    /// The [doi.org](https://www.doi.org) proxy, i.e., `https://doi.org/<DOI>` (default).
+    #[default]
+    DoiOrg,
+    /// A resolver at a fixed base URL, i.e., `<BASE>/<DOI>`.
+    BaseUrl(String),
+    /// A resolver with an arbitrary mapping from the DOI number to the URL.
+    ///
+    /// The closure is stored behind an `Arc` so that cloned [`Doi`] instances share it.
+    Custom(Arc<dyn Fn(&str) -> String + Send + Sync>),
+}
+
+impl Resolver {
+    /// Creates a [`Resolver::Custom`] from a closure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{DoiBuilder, Resolver};
+    /// let resolver = Resolver::custom(|doi| format!("https://resolver.example/?id=doi:{}", doi));
+    /// let doi = DoiBuilder::new().doi("10.1109/TCSII.2024.3366282").resolver(resolver).build();
+    /// assert_eq!(doi.https_url(), "https://resolver.example/?id=doi:10.1109/TCSII.2024.3366282");
+    /// ```
+    pub fn custom<F: Fn(&str) -> String + Send + Sync + 'static>(f: F) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Returns the URL for the DOI number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Resolver;
+    /// assert_eq!(Resolver::DoiOrg.url("10.1/x"), "https://doi.org/10.1/x");
+    /// let base = Resolver::BaseUrl("https://hdl.handle.net/".to_string());
+    /// assert_eq!(base.url("10.1/x"), "https://hdl.handle.net/10.1/x");
+    /// ```
+    pub fn url(&self, doi: &str) -> String {
+        match self {
+            Self::DoiOrg => format!("https://doi.org/{}", doi),
+            Self::BaseUrl(base) => format!("{}/{}", base.trim_end_matches('/'), doi),
+            Self::Custom(f) => f(doi),
+        }
+    }
+
+    /// Returns the URL of the DOI in the Handle API (see [`Doi::resolve_all`]).
+    ///
+    /// The API is at `<BASE>/api/handles/<DOI>` for [`Self::BaseUrl`],
+    /// and at doi.org otherwise (a custom mapping cannot be extended).
+    #[cfg(feature = "metadata")]
+    pub(crate) fn handle_api_url(&self, doi: &str) -> String {
+        let base = match self {
+            Self::BaseUrl(base) => base.trim_end_matches('/'),
+            Self::DoiOrg | Self::Custom(_) => "https://doi.org",
+        };
+        format!("{}/api/handles/{}", base, doi)
+    }
+}
+
+impl fmt::Debug for Resolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DoiOrg => write!(f, "DoiOrg"),
+            Self::BaseUrl(base) => f.debug_tuple("BaseUrl").field(base).finish(),
+            Self::Custom(_) => write!(f, "Custom(<closure>)"),
+        }
+    }
+}
+
+/// Result of resolving a DOI with [`Doi::resolve_canonical`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// The resolved URL (as returned by [`Doi::resolve`]).
+    pub url: String,
+    /// The canonical DOI exposed by the landing page, if any.
+    pub canonical_doi: Option<String>,
+}
+
+/// Trace of the requests made by [`Doi::resolve_traced`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolutionTrace {
+    /// The hops in order, from the resolver request to the final response.
+    pub hops: Vec<Hop>,
+}
+
+/// A request made while resolving a DOI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hop {
+    /// The requested URL.
+    pub url: String,
+    /// The status code of the response (e.g., `302` for a redirect).
+    pub status: u16,
+}
+
+/// Kind of the first redirect of a DOI, as returned by [`Doi::resolve_redirect_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedirectKind {
+    /// A permanent redirect (`301 Moved Permanently` or `308 Permanent Redirect`).
+    Permanent,
+    /// A temporary redirect (`302 Found`, `303 See Other`, or `307 Temporary Redirect`).
+    Temporary,
+    /// No redirect (the resolver answered directly).
+    None,
+}
+
+impl RedirectKind {
+    /// Returns the kind of a redirect with the status code.
+    fn from_status(status: u16) -> Self {
+        match status {
+            301 | 308 => Self::Permanent,
+            302 | 303 | 307 => Self::Temporary,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Built-in subset of the HSTS preload list.
+///
+/// Each entry matches the host itself and all of its subdomains,
+/// so whole preloaded top-level domains (e.g., `.dev`) are covered.
+const HSTS_PRELOAD: [&str; 6] = [
+    "app",
+    "dev",
+    "page",
+    "github.com",
+    "github.io",
+    "wikipedia.org",
+];
+
+/// Maximum number of redirects followed, the same as the `ureq` default.
+const MAX_REDIRECTS: u32 = 5;
+
+/// Checks if `host` is `domain` or one of its subdomains.
+fn host_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.')
+}
+
+/// Returns the host of a URL in ASCII form.
+///
+/// Internationalized domain names are converted to punycode (e.g., `xn--bcher-kva.example`).
+pub(crate) fn ascii_host(url: &str) -> Option<String> {
+    url::Url::parse(url)
+        .ok()?
+        .host_str()
+        .map(|host| host.to_ascii_lowercase())
+}
+
+/// Returns the DOI of a `Link` header entry if it has `rel="canonical"` and a DOI resolver URL.
+fn canonical_link_doi(link: &str) -> Option<String> {
+    let (target, params) = link.trim().strip_prefix('<')?.split_once('>')?;
+    let canonical = params.split(';').any(|param| {
+        param.trim().split_once('=').is_some_and(|(key, value)| {
+            key.trim().eq_ignore_ascii_case("rel")
+                && value
+                    .trim()
+                    .trim_matches('"')
+                    .split_whitespace()
+                    .any(|rel| rel.eq_ignore_ascii_case("canonical"))
+        })
+    });
+    let url = url::Url::parse(target).ok()?;
+    let resolver = matches!(
+        url.host_str(),
+        Some("doi.org" | "dx.doi.org" | "www.doi.org")
+    );
+    if !canonical || !resolver {
+        return None;
+    }
+    let doi = percent_decode_str(url.path().trim_start_matches('/')).decode_utf8_lossy();
+    (!doi.is_empty()).then(|| doi.into_owned())
+}
+
+impl Doi {
+    /// Resolves the DOI and returns the host of the landing page.
+    ///
+    /// Internationalized domain names are returned in their ASCII (punycode) form,
+    /// so the host is consistent regardless of how the publisher wrote the redirect.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if there is an error resolving the DOI,
+    /// or if the resolved URL has no host.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.landing_domain() {
+    ///     Ok(domain) => assert_eq!(domain, "ieeexplore.ieee.org"),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn landing_domain(&self) -> Result<String, DoiError> {
+        let resolved = self.resolve()?;
+        ascii_host(&resolved)
+            .ok_or_else(|| DoiError::Parse(format!("No host in resolved URL: {}", resolved)))
+    }
+
+    /// Resolves the DOI and returns the resolved URL together with the canonical DOI.
+    ///
+    /// When a DOI is an alias, the landing page may expose the primary DOI.
+    /// This is read on a best-effort basis from the final response headers:
+    /// a `Link` header with `rel="canonical"` pointing to a DOI resolver URL,
+    /// or else an `X-DOI` header.
+    /// The canonical DOI is `None` if neither is present.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if there is an error resolving the DOI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve_canonical() {
+    ///     Ok(resolution) => {
+    ///         println!("Resolved link: {}", resolution.url);
+    ///         if let Some(canonical_doi) = resolution.canonical_doi {
+    ///             println!("Canonical DOI: {}", canonical_doi);
+    ///         }
+    ///     },
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn resolve_canonical(&self) -> Result<Resolution, DoiError> {
+        let response = self.resolve_response()?;
+        let canonical_doi = response
+            .all("Link")
+            .into_iter()
+            .flat_map(|links| links.split(','))
+            .find_map(canonical_link_doi)
+            .or_else(|| {
+                response
+                    .header("X-DOI")
+                    .map(|doi| Doi::normalize_input(doi.to_string()))
+                    .filter(|doi| !doi.is_empty())
+            });
+        Ok(Resolution {
+            url: response.get_url().to_string(),
+            canonical_doi,
+        })
+    }
+
+    /// Resolves the DOI and returns the status code of the landing page.
+    ///
+    /// Unlike [`Self::resolve`], an error status of the landing page (e.g., a dead publisher link)
+    /// is returned as the status code rather than an error.
+    /// If the landing page rejects the HEAD request with `405 Method Not Allowed`,
+    /// it is requested again with GET.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if the DOI cannot be resolved
+    /// (including an error status from the resolver itself, e.g., an unknown DOI)
+    /// or the landing page cannot be reached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.verify_landing() {
+    ///     Ok(status) => println!("Landing page status: {}", status),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn verify_landing(&self) -> Result<u16, DoiError> {
+        self.get_doi()?; // Check if DOI is set.
+        let url = self.https_url();
+        let deadline = self.call_deadline();
+        let response = match self.resolve_request(&self.agent, &url, deadline)?.call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) if response.get_url() != url => response,
+            Err(e) => return Err(DoiError::from_call(e, &url)),
+        };
+        if response.status() != 405 {
+            return Ok(response.status());
+        }
+        let request = self.before_deadline(self.agent.get(response.get_url()), deadline)?;
+        match request.call() {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => Ok(response.status()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Resolves the DOI and returns the content length and type of the landing resource.
+    ///
+    /// The resolution is done with HEAD requests (as for [`Self::resolve`]),
+    /// so the `Content-Length` and `Content-Type` headers of the final response are returned
+    /// without downloading the resource, e.g., to check the size of a PDF before downloading it.
+    /// Either is `None` if the server omits the header (or the length is not a number).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if the DOI cannot be resolved (see [`Self::resolve`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.landing_info() {
+    ///     Ok((length, content_type)) => println!("{:?} bytes of {:?}", length, content_type),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn landing_info(&self) -> Result<(Option<u64>, Option<String>), DoiError> {
+        let response = self.resolve_response()?;
+        let length = response
+            .header("Content-Length")
+            .and_then(|length| length.trim().parse().ok());
+        let content_type = response
+            .header("Content-Type")
+            .map(|content_type| content_type.trim().to_string())
+            .filter(|content_type| !content_type.is_empty());
+        Ok((length, content_type))
+    }
+
+    /// Returns all URLs registered for the DOI, querying the Handle API.
+    ///
+    /// Most DOIs have a single URL, but some have several (multiple resolution),
+    /// for which doi.org shows a chooser page instead of redirecting.
+    /// This queries `https://doi.org/api/handles/<DOI>` (or `<BASE>/api/handles/<DOI>`
+    /// with [`Resolver::BaseUrl`]) and returns the values of the `URL` type in their registered order.
+    /// Locations registered in other types (e.g., `10320/loc`) are not included.
+    /// This requires the `metadata` feature (for parsing the JSON response).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns [`DoiError::NotFound`] if the handle does not exist.
+    /// Returns a [`DoiError`] if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve_all() {
+    ///     Ok(urls) => println!("Registered URLs: {:?}", urls),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "metadata")]
+    pub fn resolve_all(&self) -> Result<Vec<String>, DoiError> {
+        self.handle_values("URL")
+    }
+
+    /// Returns the target DOI if the DOI is an alias, querying the Handle API.
+    ///
+    /// A handle can be an alias (synonym) of another one, listed as an `HS_ALIAS` value,
+    /// in which case the target is the canonical DOI.
+    /// The returned [`Doi`] shares the configuration of this one.
+    /// Returns `None` if the DOI is not an alias.
+    /// This requires the `metadata` feature (for parsing the JSON response).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns [`DoiError::NotFound`] if the handle does not exist.
+    /// Returns a [`DoiError`] if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.alias_target() {
+    ///     Ok(Some(target)) => println!("Alias of {}", target),
+    ///     Ok(None) => println!("Not an alias"),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "metadata")]
+    pub fn alias_target(&self) -> Result<Option<Doi>, DoiError> {
+        Ok(self.handle_values("HS_ALIAS")?.first().map(|target| {
+            let mut doi = self.clone();
+            doi.set_doi(target);
+            doi
+        }))
+    }
+
+    /// Returns the string values of the `type` (e.g., `URL`) of the handle, in their registered order.
+    #[cfg(feature = "metadata")]
+    fn handle_values(&self, r#type: &str) -> Result<Vec<String>, DoiError> {
+        let url = self.resolver.handle_api_url(&self.get_doi()?);
+        let request = self.before_deadline(self.agent.get(&url), self.call_deadline())?;
+        let json: crate::JsonValue = request
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|e| DoiError::from_call(e, &url))?
+            .into_json()
+            .map_err(|e| DoiError::Parse(format!("Error parsing JSON: {}", e)))?;
+        // Response code 100 means "handle not found" (usually with a 404 status).
+        if json["responseCode"].as_u64() == Some(100) {
+            return Err(DoiError::NotFound);
+        }
+        Ok(json["values"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|value| value["type"].as_str() == Some(r#type))
+            .filter_map(|value| value["data"]["value"].as_str())
+            .map(|value| value.to_string())
+            .collect())
+    }
+
+    /// Checks if the DOI exists, i.e., the resolver does not respond with `404 Not Found`.
+    ///
+    /// An error status of the landing page (e.g., a dead publisher link)
+    /// still means that the DOI exists (see [`Self::verify_landing`] to check the landing page).
+    /// With the `cache` feature, the resolution is taken from the `DoiCache` if enabled
+    /// (including a cached "not found").
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if the resolver cannot be reached
+    /// or responds with an error status other than 404.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.exists() {
+    ///     Ok(exists) => println!("DOI exists: {}", exists),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn exists(&self) -> Result<bool, DoiError> {
+        self.get_doi()?;
+        let url = self.https_url();
+        match self.resolve() {
+            Ok(_) => Ok(true),
+            Err(DoiError::NotFound) => Ok(false),
+            Err(DoiError::Http(e)) => match &*e {
+                ureq::Error::Status(_, response) if response.get_url() != url => Ok(true),
+                _ => Err(DoiError::Http(e)),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Checks if each of the DOIs exists (see [`Self::exists`]) on a pool of `threads` threads.
+    ///
+    /// The checks are blocking HEAD requests, at most `threads` of which run at a time
+    /// (at least one). Each DOI uses its own agent, and clones of a [`Doi`]
+    /// (or DOIs built from the same [`DoiBuilder`](crate::DoiBuilder)) share their connection pool.
+    /// The results are in the order of `dois`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let dois = vec![
+    ///     Doi::new("10.1109/TCSII.2024.3366282"),
+    ///     Doi::new("10.1145/3643832.3661865"),
+    ///     Doi::default(),
+    /// ];
+    /// let results = Doi::exists_many(&dois, 2);
+    /// assert_eq!(results.len(), 3);
+    /// assert!(results[2].is_err());
+    /// for (doi, result) in dois.iter().zip(results) {
+    ///     match result {
+    ///         Ok(exists) => println!("{}: {}", doi, exists),
+    ///         Err(e) => eprintln!("{}: Error: {}", doi, e),
+    ///     }
+    /// }
+    /// ```
+    pub fn exists_many(dois: &[Doi], threads: usize) -> Vec<Result<bool, DoiError>> {
+        pool::map(dois, threads, Doi::exists)
+    }
+
+    /// Resolves each of the DOIs (see [`Self::resolve`]) one after another, sharing `agent`.
+    ///
+    /// All requests go through `agent`, so its connection pool is reused
+    /// instead of connecting anew for each DOI.
+    /// The agent replaces the one of each DOI (including its proxy and timeouts),
+    /// while the other settings of each DOI (e.g., the resolver) still apply.
+    /// DOIs with the HSTS upgrade (see [`DoiBuilder::hsts_upgrade`](crate::DoiBuilder::hsts_upgrade))
+    /// are the exception: their redirects are followed hop by hop through an agent that
+    /// does not follow redirects, which cannot be derived from `agent`,
+    /// so they keep using their own agent.
+    /// The results are guaranteed to be in the order of `dois`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{Doi, DoiBuilder};
+    /// let dois = vec![
+    ///     Doi::new("10.1109/TCSII.2024.3366282"),
+    ///     Doi::new("10.1145/3643832.3661865"),
+    ///     Doi::default(),
+    /// ];
+    /// let results = Doi::resolve_many(&dois, &DoiBuilder::default_agent());
+    /// assert_eq!(results.len(), 3);
+    /// assert!(results[2].is_err());
+    /// for (doi, result) in dois.iter().zip(results) {
+    ///     match result {
+    ///         Ok(link) => println!("{}: {}", doi, link),
+    ///         Err(e) => eprintln!("{}: Error: {}", doi, e),
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_many(dois: &[Doi], agent: &Agent) -> Vec<Result<String, DoiError>> {
+        dois.iter()
+            .map(|doi| doi.with_agent(agent).resolve())
+            .collect()
+    }
+
+    /// Resolves each of the DOIs like [`Self::resolve_many`], on a pool of `threads` threads.
+    ///
+    /// At most `threads` requests (at least one) run at a time, all sharing `agent`.
+    /// The results are guaranteed to be in the order of `dois`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{Doi, DoiBuilder};
+    /// let dois = vec![
+    ///     Doi::new("10.1109/TCSII.2024.3366282"),
+    ///     Doi::new("10.1145/3643832.3661865"),
+    /// ];
+    /// let results = Doi::resolve_many_parallel(&dois, &DoiBuilder::default_agent(), 2);
+    /// assert_eq!(results.len(), 2);
+    /// ```
+    pub fn resolve_many_parallel(
+        dois: &[Doi],
+        agent: &Agent,
+        threads: usize,
+    ) -> Vec<Result<String, DoiError>> {
+        pool::map(dois, threads, |doi| doi.with_agent(agent).resolve())
+    }
+
+    /// Returns a clone of the DOI making its requests through `agent`.
+    ///
+    /// The agent for following redirects hop by hop is kept,
+    /// since `agent` follows redirects by itself.
+    /// The timeout of `agent` is unknown, so the configured timeout no longer caps the deadline.
+    fn with_agent(&self, agent: &Agent) -> Doi {
+        let mut doi = self.clone();
+        doi.agent = agent.clone();
+        doi.timeout = None;
+        doi
+    }
+
+    /// Upgrades an `http` URL to `https` if its host is HSTS-preloaded.
+    ///
+    /// The hosts are the built-in subset plus those added via
+    /// [`DoiBuilder::hsts_hosts`](crate::DoiBuilder::hsts_hosts).
+    /// The URL is returned unchanged if the HSTS upgrade is disabled,
+    /// the URL is not `http`, or the host is not in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{Doi, DoiBuilder};
+    /// let doi = DoiBuilder::new().doi("10.1109/TCSII.2024.3366282").hsts_upgrade(true).build();
+    /// assert_eq!(doi.hsts_upgrade_url("http://example.dev/paper"), "https://example.dev/paper");
+    /// assert_eq!(doi.hsts_upgrade_url("http://example.com/paper"), "http://example.com/paper");
+    /// let no_hsts = Doi::new("10.1109/TCSII.2024.3366282");
+    /// assert_eq!(no_hsts.hsts_upgrade_url("http://example.dev/paper"), "http://example.dev/paper");
+    /// ```
+    pub fn hsts_upgrade_url(&self, url: &str) -> String {
+        let Some(hosts) = &self.hsts_hosts else {
+            return url.to_string();
+        };
+        let Ok(mut parsed) = url::Url::parse(url) else {
+            return url.to_string();
+        };
+        let preloaded = parsed.scheme() == "http"
+            && ascii_host(url).is_some_and(|host| {
+                HSTS_PRELOAD
+                    .iter()
+                    .copied()
+                    .chain(hosts.iter().map(String::as_str))
+                    .any(|domain| host_matches(&host, domain))
+            });
+        if preloaded && parsed.set_scheme("https").is_ok() {
+            if parsed.port() == Some(80) {
+                let _ = parsed.set_port(None);
+            }
+            return parsed.to_string();
+        }
+        url.to_string()
+    }
+
+    /// Resolves the DOI and returns the trace of all requests made.
+    ///
+    /// The redirects are followed hop by hop, starting with the request to the resolver
+    /// (e.g., doi.org) and ending with the final response (e.g., the landing page),
+    /// recording the URL and status code of each hop.
+    /// The HSTS upgrade (see [`DoiBuilder::hsts_upgrade`](crate::DoiBuilder::hsts_upgrade))
+    /// is applied if enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if there is an error resolving the DOI
+    /// (including too many redirects).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve_traced() {
+    ///     Ok(trace) => {
+    ///         for hop in &trace.hops {
+    ///             println!("{} {}", hop.status, hop.url);
+    ///         }
+    ///         assert_eq!(trace.hops[0].url, "https://doi.org/10.1109/TCSII.2024.3366282");
+    ///     },
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn resolve_traced(&self) -> Result<ResolutionTrace, DoiError> {
+        self.get_doi()?; // Check if DOI is set.
+        let mut hops = Vec::new();
+        self.with_retries(|deadline| {
+            hops.clear();
+            self.resolve_hops(&mut hops, deadline)
+        })?;
+        Ok(ResolutionTrace { hops })
+    }
+
+    /// Resolves the DOI and returns the resolved URL with the kind of the first redirect.
+    ///
+    /// The kind is taken from the status code of the resolver response (the first hop,
+    /// see [`Self::resolve_traced`]), e.g., for deciding how long to cache the resolved URL.
+    /// Later redirects (e.g., within the publisher site) do not affect it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoiError`] if there is an error resolving the DOI (see [`Self::resolve`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::{Doi, RedirectKind};
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.resolve_redirect_kind() {
+    ///     Ok((url, RedirectKind::Permanent)) => println!("Permanently at {}", url),
+    ///     Ok((url, _)) => println!("Currently at {}", url),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn resolve_redirect_kind(&self) -> Result<(String, RedirectKind), DoiError> {
+        self.get_doi()?; // Check if DOI is set.
+        let mut hops = Vec::new();
+        let response = self.with_retries(|deadline| {
+            hops.clear();
+            self.resolve_hops(&mut hops, deadline)
+        })?;
+        let kind = hops.first().map_or(RedirectKind::None, |hop| {
+            RedirectKind::from_status(hop.status)
+        });
+        Ok((response.get_url().to_string(), kind))
+    }
+
+    /// Resolves the DOI by following the redirects hop by hop, recording each hop in `hops`.
+    ///
+    /// Each redirect target goes through [`Self::hsts_upgrade_url`] before it is requested.
+    pub(crate) fn resolve_hops(
+        &self,
+        hops: &mut Vec<Hop>,
+        deadline: Option<Instant>,
+    ) -> Result<ureq::Response, DoiError> {
+        self.get_doi()?; // Check if DOI is set.
+        let doi_url = self.https_url();
+        let mut url = doi_url.clone();
+        for _ in 0..=MAX_REDIRECTS {
+            let request = self.resolve_request(&self.hop_agent, &url, deadline)?;
+            let response = self.resolved_response(request.call(), &doi_url)?;
+            hops.push(Hop {
+                url: url.clone(),
+                status: response.status(),
+            });
+            match response.status() {
+                301 | 302 | 303 | 307 | 308 => {
+                    let location = response.header("Location").ok_or_else(|| {
+                        DoiError::Redirect("Redirect without a Location header".to_string())
+                    })?;
+                    let next = url::Url::parse(&url)
+                        .and_then(|url| url.join(location))
+                        .map_err(|e| {
+                            DoiError::Redirect(format!("Invalid redirect location: {}", e))
+                        })?;
+                    url = self.hsts_upgrade_url(next.as_str());
+                }
+                _ => return Ok(response),
+            }
+        }
+        Err(DoiError::Redirect("Too many redirects".to_string()))
+    }
+}
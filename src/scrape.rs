@@ -0,0 +1,248 @@
+use crate::metadata::parse_date;
+use crate::{Doi, DoiError, DoiMetadata, DoiMetadataPerson};
+
+/// Meta tag names for the title, in order of precedence.
+const TITLE_TAGS: [&str; 2] = ["citation_title", "dc.title"];
+/// Meta tag names for the authors, in order of precedence.
+const AUTHOR_TAGS: [&str; 2] = ["citation_author", "dc.creator"];
+/// Meta tag names for the publication date, in order of precedence.
+const DATE_TAGS: [&str; 5] = [
+    "citation_publication_date",
+    "citation_date",
+    "citation_online_date",
+    "dc.date.issued",
+    "dc.date",
+];
+
+impl Doi {
+    /// Fetches metadata for the DOI, falling back to scraping the landing page.
+    ///
+    /// This first tries [`Self::metadata`]. If the negotiated metadata fails or is empty
+    /// (no title and no authors), the landing page is fetched as HTML and its
+    /// [Highwire Press](https://scholar.google.com/intl/en/scholar/inclusion.html#indexing)
+    /// (`citation_*`) or Dublin Core (`DC.*`) meta tags are parsed for the title,
+    /// the authors, and the publication date (stored as `published`).
+    /// The negotiated fields take precedence over the scraped ones.
+    ///
+    /// Scraping is best-effort: landing pages are not standardized,
+    /// may require JavaScript, or may block automated requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns a [`DoiError`] if the negotiated metadata fails and scraping
+    /// the landing page fails or finds no title (the negotiation error is returned).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.metadata_with_scrape() {
+    ///     Ok(metadata) => println!("Paper Title: {}", metadata.title.unwrap_or("<unknown>".to_string())),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn metadata_with_scrape(&self) -> Result<DoiMetadata, DoiError> {
+        let negotiated = self.metadata();
+        if let Ok(metadata) = &negotiated {
+            if metadata.title.is_some() || metadata.authors.as_ref().is_some_and(|a| !a.is_empty())
+            {
+                return negotiated;
+            }
+        }
+        let scraped = self
+            .metadata_bytes("text/html")
+            .map(|html| DoiMetadata::from_meta_tags(&String::from_utf8_lossy(&html)));
+        match (negotiated, scraped) {
+            (Ok(mut metadata), Ok(scraped)) => {
+                metadata.title = metadata.title.or(scraped.title);
+                metadata.authors = metadata
+                    .authors
+                    .filter(|a| !a.is_empty())
+                    .or(scraped.authors);
+                metadata.published = metadata.published.or(scraped.published);
+                Ok(metadata)
+            }
+            (Ok(metadata), Err(_)) => Ok(metadata),
+            (Err(_), Ok(mut scraped)) if scraped.title.is_some() => {
+                scraped.doi = self.get_doi()?;
+                Ok(scraped)
+            }
+            (Err(e), _) => Err(e),
+        }
+    }
+}
+
+impl DoiMetadata {
+    /// Extracts the metadata from the meta tags of an HTML page.
+    ///
+    /// The DOI is left empty.
+    fn from_meta_tags(html: &str) -> Self {
+        let tags = meta_tags(html);
+        let values = |names: &[&str]| -> Vec<&str> {
+            names
+                .iter()
+                .map(|name| {
+                    tags.iter()
+                        .filter(|(tag, content)| tag == name && !content.is_empty())
+                        .map(|(_, content)| content.as_str())
+                        .collect::<Vec<_>>()
+                })
+                .find(|values| !values.is_empty())
+                .unwrap_or_default()
+        };
+        let authors: Vec<_> = values(&AUTHOR_TAGS).into_iter().map(parse_person).collect();
+        Self {
+            title: values(&TITLE_TAGS).first().map(|s| s.to_string()),
+            authors: (!authors.is_empty()).then_some(authors),
+            published: values(&DATE_TAGS).into_iter().find_map(parse_date),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parses a person name from a meta tag, either "Family, Given" or "Given Family".
+///
+/// A name without a comma or space is kept as the literal name.
+fn parse_person(name: &str) -> DoiMetadataPerson {
+    let part = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+    let (given, family) = if let Some((family, given)) = name.split_once(',') {
+        (given, family)
+    } else if let Some((given, family)) = name.trim().rsplit_once(' ') {
+        (given, family)
+    } else {
+        return DoiMetadataPerson {
+            literal: part(name),
+            ..Default::default()
+        };
+    };
+    DoiMetadataPerson {
+        given: part(given),
+        family: part(family),
+        ..Default::default()
+    }
+}
+
+/// Returns the `(name, content)` pairs of the `<meta>` tags in an HTML page.
+///
+/// The name is taken from the `name` or `property` attribute and lowercased.
+/// This is a lightweight scan rather than a full HTML parser.
+fn meta_tags(html: &str) -> Vec<(String, String)> {
+    // ASCII lowercasing keeps the byte offsets valid for `html`.
+    let lower = html.to_ascii_lowercase();
+    let mut tags = Vec::new();
+    let mut rest = 0;
+    while let Some(start) = lower[rest..].find("<meta") {
+        let start = rest + start + "<meta".len();
+        let Some(end) = lower[start..].find('>') else {
+            break;
+        };
+        rest = start + end;
+        let attributes = attributes(&html[start..rest]);
+        let attribute = |key: &str| {
+            attributes
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str())
+        };
+        if let (Some(name), Some(content)) = (
+            attribute("name").or_else(|| attribute("property")),
+            attribute("content"),
+        ) {
+            tags.push((name.to_ascii_lowercase(), unescape(content.trim())));
+        }
+    }
+    tags
+}
+
+/// Parses the attributes of an HTML tag into lowercase names and raw values.
+fn attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut chars = tag.char_indices().peekable();
+    while let Some(&(i, c)) = chars.peek() {
+        if c.is_whitespace() || c == '/' {
+            chars.next();
+            continue;
+        }
+        let mut name_end = i;
+        while let Some(&(j, c)) = chars.peek() {
+            if c.is_whitespace() || c == '=' || c == '/' {
+                break;
+            }
+            name_end = j + c.len_utf8();
+            chars.next();
+        }
+        let name = tag[i..name_end].to_ascii_lowercase();
+        while chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+            chars.next();
+        }
+        let mut value = String::new();
+        if chars.peek().is_some_and(|&(_, c)| c == '=') {
+            chars.next();
+            while chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+                chars.next();
+            }
+            match chars.peek() {
+                Some(&(_, quote @ ('"' | '\''))) => {
+                    chars.next();
+                    value.extend(chars.by_ref().map(|(_, c)| c).take_while(|&c| c != quote));
+                }
+                _ => {
+                    while let Some(&(_, c)) = chars.peek() {
+                        if c.is_whitespace() {
+                            break;
+                        }
+                        value.push(c);
+                        chars.next();
+                    }
+                }
+            }
+        }
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+    }
+    attributes
+}
+
+/// Decodes the common HTML character references in an attribute value.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => '\u{a0}',
+                entity => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                unescaped.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
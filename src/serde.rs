@@ -0,0 +1,104 @@
+use crate::Doi;
+#[cfg(feature = "metadata")]
+use crate::DoiMetadataType;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+
+/// Serializes the DOI as its bare string, or `null` if it is not set.
+///
+/// Only the DOI number is serialized; the configuration (e.g., the agent) is not.
+/// This requires the `serde` feature.
+impl Serialize for Doi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.doi {
+            Some(doi) => serializer.serialize_str(doi),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Deserializes the DOI from a string, or `null` for an unset DOI ([`Doi::default`]).
+///
+/// The string is parsed via [`Doi::from_str`](#method.from_str) (e.g., `doi:10.1/x`),
+/// falling back to [`Doi::from_url`] (e.g., `https://www.doi.org/10.1/x?query`).
+/// The deserialized [`Doi`] has the default configuration.
+/// This requires the `serde` feature.
+impl<'de> Deserialize<'de> for Doi {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(DoiVisitor)
+    }
+}
+
+/// Visitor for a DOI string or `null`.
+struct DoiVisitor;
+
+impl<'de> Visitor<'de> for DoiVisitor {
+    type Value = Doi;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a DOI string or null")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Doi, E> {
+        value
+            .parse()
+            .or_else(|e| Doi::from_url(value).map_err(|_| e))
+            .map_err(E::custom)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Doi, E> {
+        Ok(Doi::default())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Doi, E> {
+        Ok(Doi::default())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Doi, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+/// Serializes the metadata type as its CSL string (see [`DoiMetadataType::as_str`]),
+/// e.g., `article-journal` rather than the variant name.
+///
+/// A [`DoiMetadataType::MISC`] type is serialized as its raw string.
+/// This requires the `serde` and `metadata` features.
+#[cfg(feature = "metadata")]
+impl Serialize for DoiMetadataType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserializes the metadata type from a CSL type string via [`DoiMetadataType::new`].
+///
+/// An unknown string (e.g., the Crossref type `journal-article`) becomes a
+/// [`DoiMetadataType::MISC`] type, so its raw string round-trips.
+/// However, a `MISC` type holding a CSL string (e.g., `MISC("article-journal")`)
+/// comes back as the known type (`ArticleJournal`).
+/// This requires the `serde` and `metadata` features.
+#[cfg(feature = "metadata")]
+impl<'de> Deserialize<'de> for DoiMetadataType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DoiMetadataTypeVisitor)
+    }
+}
+
+/// Visitor for a metadata type string.
+#[cfg(feature = "metadata")]
+struct DoiMetadataTypeVisitor;
+
+#[cfg(feature = "metadata")]
+impl<'de> Visitor<'de> for DoiMetadataTypeVisitor {
+    type Value = DoiMetadataType;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a CSL type string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<DoiMetadataType, E> {
+        Ok(DoiMetadataType::new(value))
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Doi, DoiError, JsonValue};
+
+/// The default base URL of the shortDOI service (see [`DoiBuilder::shortdoi_service`](crate::DoiBuilder::shortdoi_service)).
+pub(crate) const SHORTDOI_ORG: &str = "https://shortdoi.org";
+
+/// ShortDOIs (e.g., `10/b6zq`) of the shortDOI service.
+///
+/// The service is hosted at [shortdoi.org](https://shortdoi.org), not at doi.org.
+/// A shortDOI resolves at doi.org like a full DOI (see [`Doi::resolve`]),
+/// but only the service maps it to the full DOI.
+impl Doi {
+    /// Checks if the DOI is a shortDOI, i.e., of the form `10/<code>`
+    /// with an alphanumeric code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// assert!(Doi::new("10/b6zq").is_short());
+    /// assert!(Doi::new("https://doi.org/10/B6ZQ").is_short());
+    /// assert!(!Doi::new("10.1109/TCSII.2024.3366282").is_short());
+    /// assert!(!Doi::new("10/").is_short());
+    /// assert!(!Doi::default().is_short());
+    /// ```
+    pub fn is_short(&self) -> bool {
+        self.doi
+            .as_deref()
+            .and_then(|doi| doi.split_once('/'))
+            .is_some_and(|(prefix, code)| {
+                prefix == "10"
+                    && !code.is_empty()
+                    && code.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+    }
+
+    /// Expands the shortDOI to the full DOI by querying the shortDOI service.
+    ///
+    /// The returned [`Doi`] shares the configuration (e.g., the agent) of this one.
+    /// The service is at `https://shortdoi.org` unless configured otherwise via
+    /// [`DoiBuilder::shortdoi_service`](crate::DoiBuilder::shortdoi_service).
+    /// This requires the `metadata` feature (for parsing the JSON response).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns [`DoiError::Parse`] if the DOI is not a shortDOI (see [`Self::is_short`])
+    /// or the response cannot be parsed.
+    /// Returns a [`DoiError`] if the request to the shortDOI service fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10/b6zq");
+    /// match doi.resolve_short() {
+    ///     Ok(full) => println!("Full DOI: {}", full),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// assert!(Doi::new("10.1109/TCSII.2024.3366282").resolve_short().is_err());
+    /// ```
+    pub fn resolve_short(&self) -> Result<Doi, DoiError> {
+        let doi = self.get_doi()?;
+        if !self.is_short() {
+            return Err(DoiError::Parse(format!("{:?} is not a shortDOI", doi)));
+        }
+        self.shortdoi_field(&doi, "DOI")
+    }
+
+    /// Requests the shortDOI of the DOI from the shortDOI service.
+    ///
+    /// The service creates a shortDOI if the DOI does not have one yet.
+    /// A shortDOI is returned as is, without a request.
+    /// The returned [`Doi`] shares the configuration (e.g., the agent) of this one.
+    /// This requires the `metadata` feature (for parsing the JSON response).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoiError::NotSet`] if the DOI is not set, i.e., `None`.
+    /// Returns [`DoiError::Parse`] if the response cannot be parsed.
+    /// Returns a [`DoiError`] if the request to the shortDOI service fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use doi::Doi;
+    /// let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    /// match doi.to_short() {
+    ///     Ok(short) => println!("ShortDOI: {}", short),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn to_short(&self) -> Result<Doi, DoiError> {
+        let doi = self.get_doi()?;
+        if self.is_short() {
+            return Ok(self.clone());
+        }
+        self.shortdoi_field(&doi, "ShortDOI")
+    }
+
+    /// Queries the shortDOI service for `doi` and returns the DOI in `field` of the response.
+    fn shortdoi_field(&self, doi: &str, field: &str) -> Result<Doi, DoiError> {
+        let url = format!(
+            "{}/{}?format=json",
+            self.shortdoi_service.trim_end_matches('/'),
+            doi
+        );
+        let json: JsonValue = self
+            .with_retries(|deadline| {
+                self.before_deadline(self.agent.get(&url), deadline)?
+                    .set("Accept", "application/json")
+                    .call()
+                    .map_err(|e| DoiError::from_call(e, &url))
+            })?
+            .into_json()
+            .map_err(|e| DoiError::Parse(format!("Error parsing JSON: {}", e)))?;
+        let value = json[field]
+            .as_str()
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                DoiError::Parse(format!("Missing `{}` in the shortDOI response", field))
+            })?;
+        let mut result = self.clone();
+        result.set_doi(value);
+        Ok(result)
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "cache")]
+
+mod common;
+
+use common::{MockResponse, MockServer};
+use doi::{DoiBuilder, Resolver};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn resolve_concurrent_single_flight() {
+    let server = MockServer::start(|_| MockResponse::status(200).delay(Duration::from_millis(300)));
+    let doi = server.builder("10.1/single-flight").cache(true).build();
+    let resolved: Vec<_> = thread::scope(|s| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| s.spawn(|| doi.resolve().map_err(|e| e.to_string())))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for url in resolved {
+        assert_eq!(url.unwrap(), format!("{}/10.1/single-flight", server.url()));
+    }
+    assert_eq!(server.requests().len(), 1);
+    // Later calls are answered from the cache.
+    doi.resolve().unwrap();
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn resolve_concurrent_panic() {
+    let resolver = Resolver::custom(|_| {
+        thread::sleep(Duration::from_millis(300));
+        panic!("resolver panicked");
+    });
+    let doi = DoiBuilder::new()
+        .doi("10.1/panic")
+        .env_proxy(false)
+        .resolver(resolver)
+        .cache(true)
+        .build();
+    thread::scope(|s| {
+        let panicked = s.spawn(|| doi.resolve());
+        thread::sleep(Duration::from_millis(100));
+        // The waiter gets an error instead of blocking forever.
+        let waiter = s.spawn(|| doi.resolve().map_err(|e| e.to_string()));
+        assert!(waiter.join().unwrap().unwrap_err().contains("panicked"));
+        assert!(panicked.join().is_err());
+    });
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn metadata_negative_cache() {
+    let server = MockServer::start(|_| MockResponse::status(404));
+    let doi = server
+        .builder("10.1/missing")
+        .negative_cache_ttl(Duration::from_secs(60))
+        .build();
+    assert!(doi.metadata().is_err());
+    assert!(doi.metadata().is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn exists_negative_cache() {
+    let server = MockServer::start(|_| MockResponse::status(404));
+    let doi = server
+        .builder("10.1/missing")
+        .negative_cache_ttl(Duration::from_secs(60))
+        .build();
+    assert!(!doi.exists().unwrap());
+    assert!(!doi.exists().unwrap());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn resolve_errors_not_cached_without_ttl() {
+    let server = MockServer::start(|_| MockResponse::status(404));
+    let doi = server.builder("10.1/missing").cache(true).build();
+    assert!(doi.resolve().is_err());
+    assert!(doi.resolve().is_err());
+    assert_eq!(server.requests().len(), 2);
+}
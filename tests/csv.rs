@@ -0,0 +1,31 @@
+#![cfg(feature = "csv")]
+
+mod common;
+
+use common::{MockResponse, MockServer};
+use doi::write_csv;
+
+#[test]
+fn write_csv_rows() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/missing" => MockResponse::status(404),
+        _ if request.method == "HEAD" => MockResponse::status(200),
+        _ => MockResponse::json(r#"{"title": "Commas, and \"Quotes\""}"#),
+    });
+    let dois = [server.doi("10.1/found"), server.doi("10.1/missing")];
+    let mut out = Vec::new();
+    write_csv(&dois, &mut out).unwrap();
+    let csv = String::from_utf8(out).unwrap();
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines[0], "doi,resolved_url,title,error");
+    assert_eq!(
+        lines[1],
+        format!(
+            r#"10.1/found,{}/10.1/found,"Commas, and ""Quotes""","#,
+            server.url()
+        )
+    );
+    assert!(lines[2].starts_with("10.1/missing,,,"));
+    assert!(lines[2].contains("404"));
+    assert_eq!(lines.len(), 3);
+}
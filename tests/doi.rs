@@ -0,0 +1,161 @@
+use doi::{Doi, DoiError};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+
+#[test]
+fn from_url_query_and_fragment() {
+    for url in [
+        "https://doi.org/10.1145/3643832.3661865?utm_source=feed",
+        "https://dx.doi.org/10.1145/3643832.3661865#citations",
+        "http://www.doi.org/10.1145/3643832.3661865?a=1&b=2#top",
+        " https://doi.org/10.1145%2F3643832.3661865 ",
+    ] {
+        let doi = Doi::from_url(url).unwrap();
+        assert_eq!(
+            doi.doi.as_deref(),
+            Some("10.1145/3643832.3661865"),
+            "{}",
+            url
+        );
+    }
+}
+
+#[test]
+fn from_url_rejects_non_resolvers() {
+    let cases = [
+        (
+            "https://example.org/10.1145/3643832.3661865",
+            "the host is not a DOI resolver",
+        ),
+        (
+            "https://doi.org.evil.com/10.1145/3643832.3661865",
+            "the host is not a DOI resolver",
+        ),
+        ("mailto:someone@doi.org", "not an HTTP(S) URL"),
+        ("doi.org/10.1145/3643832.3661865", "not a URL"),
+        (
+            "https://doi.org/?10.1145/3643832.3661865",
+            "missing `/` between the prefix and the suffix",
+        ),
+        ("https://doi.org/10.1145/", "the suffix is empty"),
+    ];
+    for (url, reason) in cases {
+        let error = Doi::from_url(url).unwrap_err();
+        assert!(error.to_string().ends_with(reason), "{}: {}", url, error);
+        assert!(error.to_string().contains(url), "{}: {}", url, error);
+    }
+}
+
+#[test]
+fn prefix_and_suffix_multi_slash() {
+    let cases = [
+        (
+            "10.1109/TCSII.2024.3366282",
+            "10.1109",
+            "TCSII.2024.3366282",
+        ),
+        (
+            "10.1007/978-3-030-12345-6_7",
+            "10.1007",
+            "978-3-030-12345-6_7",
+        ),
+        ("10.1000.10/a/b/c", "10.1000.10", "a/b/c"),
+        (
+            "https://doi.org/10.1002/(SICI)1097-4571/",
+            "10.1002",
+            "(SICI)1097-4571/",
+        ),
+    ];
+    for (input, prefix, suffix) in cases {
+        let doi = Doi::new(input);
+        assert_eq!(doi.prefix().unwrap(), prefix, "{}", input);
+        assert_eq!(doi.suffix().unwrap(), suffix, "{}", input);
+    }
+    assert!(matches!(Doi::default().suffix(), Err(DoiError::NotSet)));
+    assert!(matches!(
+        Doi::new("abc/def").prefix(),
+        Err(DoiError::Parse(_))
+    ));
+}
+
+#[test]
+// The ordering only depends on the DOI number, not the agent state.
+#[allow(clippy::mutable_key_type)]
+fn btree_set_deduplicates_case_variants() {
+    let dois = [
+        Doi::new("10.1145/3643832.3661865"),
+        Doi::new("10.1109/TCSII.2024.3366282"),
+        Doi::new("https://doi.org/10.1109/tcsii.2024.3366282"),
+        Doi::default(),
+        Doi::new("10.1109/Tcsii.2024.3366282"),
+        Doi::default(),
+    ];
+    let set: BTreeSet<_> = dois.iter().cloned().collect();
+    assert_eq!(set.len(), 3);
+    let sorted: Vec<_> = set
+        .iter()
+        .map(|doi| doi.doi.as_ref().map(|doi| doi.to_lowercase()))
+        .collect();
+    assert_eq!(
+        sorted,
+        [
+            None,
+            Some("10.1109/tcsii.2024.3366282".to_string()),
+            Some("10.1145/3643832.3661865".to_string())
+        ]
+    );
+    for a in &dois {
+        for b in &dois {
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+        }
+    }
+}
+
+#[test]
+// The hash only depends on the DOI number, not the agent state.
+#[allow(clippy::mutable_key_type)]
+fn hash_set_deduplicates_case_variants() {
+    let mut set = HashSet::new();
+    assert!(set.insert(Doi::new("10.1109/TCSII.2024.3366282")));
+    assert!(!set.insert(Doi::new("10.1109/tcsii.2024.3366282")));
+    assert_eq!(set.len(), 1);
+    assert!(set.contains(&Doi::new("doi:10.1109/TcSiI.2024.3366282")));
+    assert!(set.insert(Doi::default()));
+    assert!(!set.insert(Doi::default()));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn from_parts_joins_prefix_and_suffix() {
+    let expected = Doi::new("10.1109/TCSII.2024.3366282");
+    for (prefix, suffix) in [
+        ("10.1109", "TCSII.2024.3366282"),
+        ("10.1109/", "TCSII.2024.3366282"),
+        ("10.1109", "/TCSII.2024.3366282"),
+        ("10.1109//", "//TCSII.2024.3366282"),
+        (" 10.1109 ", " TCSII.2024.3366282 "),
+    ] {
+        let doi = Doi::from_parts(prefix, suffix);
+        assert_eq!(doi, expected, "{:?} + {:?}", prefix, suffix);
+        assert_eq!(doi.prefix().unwrap(), "10.1109");
+        assert_eq!(doi.suffix().unwrap(), "TCSII.2024.3366282");
+    }
+    // Slashes within the suffix are kept.
+    let doi = Doi::from_parts("10.1000.10", "a/b/c");
+    assert_eq!(doi.doi.as_deref(), Some("10.1000.10/a/b/c"));
+}
+
+#[test]
+fn new_non_ascii_input() {
+    // The prefix check must not slice inside a multi-byte character.
+    assert_eq!(Doi::new("abcé").doi.as_deref(), Some("abcé"));
+    assert_eq!(Doi::new("ééé").doi.as_deref(), Some("ééé"));
+    assert_eq!(
+        Doi::new("doi:10.1000/café").doi.as_deref(),
+        Some("10.1000/café")
+    );
+    assert_eq!(
+        Doi::new("https://doi.org/10.1000/ü").doi.as_deref(),
+        Some("10.1000/ü")
+    );
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "metadata")]
+
+use doi::{DoiMetadata, DoiMetadataDate, DoiMetadataType, JsonValue};
+use std::fs;
+use std::path::Path;
+
+/// Loads the JSON fixture `tests/fixtures/<name>.json` (modeled on real responses, not recorded).
+fn fixture(name: &str) -> JsonValue {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{}.json", name));
+    let json = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    ureq::serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Extracts the metadata of the fixture `name`.
+fn metadata(name: &str) -> DoiMetadata {
+    DoiMetadata::from_json(&fixture(name))
+}
+
+fn iso8601(date: &Option<DoiMetadataDate>) -> Option<String> {
+    date.as_ref().and_then(DoiMetadataDate::to_iso8601)
+}
+
+#[test]
+fn fixture_crossref_article() {
+    let metadata = metadata("article");
+    assert_eq!(metadata.doi, "10.1109/TCSII.2024.3366282");
+    assert_eq!(metadata.r#type, Some(DoiMetadataType::ArticleJournal));
+    assert_eq!(
+        metadata.container_title.as_deref(),
+        Some("IEEE Transactions on Circuits and Systems II: Express Briefs")
+    );
+    assert_eq!(
+        metadata.page_range(),
+        Some(("3303".to_string(), "3307".to_string()))
+    );
+    assert_eq!(metadata.primary_issn(), Some("1549-7747"));
+    assert_eq!(iso8601(&metadata.published).as_deref(), Some("2024-07"));
+    assert!(metadata.abstract_text().is_some());
+}
+
+#[test]
+fn fixture_conference_paper() {
+    let metadata = metadata("conference");
+    assert!(metadata.is_conference_paper());
+    assert!(metadata
+        .event
+        .as_deref()
+        .unwrap()
+        .starts_with("MOBISYS '24"));
+    assert_eq!(metadata.isbn, Some(vec!["9798400705816".to_string()]));
+    assert_eq!(metadata.issn, None);
+}
+
+#[test]
+fn fixture_datacite() {
+    let metadata = metadata("datacite");
+    assert_eq!(metadata.r#type, Some(DoiMetadataType::Dataset));
+    assert_eq!(
+        metadata.title.as_deref(),
+        Some("Measurement Data for Linear Transformations")
+    );
+    assert_eq!(metadata.title_language.as_deref(), Some("en"));
+    let authors = metadata.authors.as_ref().unwrap();
+    assert_eq!(authors[0].full_name().unwrap(), "Doi-rs Contributors");
+    assert_eq!(
+        authors[1].affiliation,
+        Some(vec!["Zenodo University".to_string()])
+    );
+    assert_eq!(
+        metadata.container_doi.as_deref(),
+        Some("10.5281/zenodo.1234000")
+    );
+    assert_eq!(
+        metadata.r#abstract.as_deref(),
+        Some("Raw measurement data.")
+    );
+    assert_eq!(iso8601(&metadata.published).as_deref(), Some("2024-02-14"));
+    assert_eq!(
+        metadata.url.as_deref(),
+        Some("https://zenodo.org/records/1234567")
+    );
+}
+
+#[test]
+fn fixture_organizational_authors() {
+    let metadata = metadata("organization-authors");
+    let names: Vec<_> = metadata
+        .authors
+        .iter()
+        .flatten()
+        .map(|author| author.full_name().unwrap())
+        .collect();
+    assert_eq!(names, ["The DOI Consortium", "Plato", "Jerry Jr."]);
+    let authors = metadata.authors.as_ref().unwrap();
+    assert_eq!(authors[1].affiliation, Some(vec!["Academy".to_string()]));
+    assert_eq!(authors[2].given, None);
+    assert_eq!(metadata.number_of_pages, Some(48));
+    assert_eq!(metadata.year(), Some(2024));
+}
+
+#[test]
+fn fixture_array_titles() {
+    let metadata = metadata("array-titles");
+    assert_eq!(
+        metadata.title.as_deref(),
+        Some("Flexible High-Level Synthesis Library for Linear Transformations")
+    );
+    assert_eq!(
+        metadata.container_title.as_deref(),
+        Some("IEEE Transactions on Circuits and Systems II: Express Briefs")
+    );
+    assert_eq!(metadata.volume.as_deref(), Some("71"));
+    assert_eq!(metadata.issue.as_deref(), Some("7"));
+    assert_eq!(metadata.year(), Some(2024));
+}
+
+#[test]
+fn fixture_clinical_trial() {
+    let metadata = metadata("clinical-trial");
+    assert_eq!(
+        metadata.title.as_deref(),
+        Some("A Registered Clinical Study")
+    );
+    assert_eq!(
+        metadata.clinical_trials,
+        Some(vec![(
+            "10.18810/clinical-trials-gov".to_string(),
+            "NCT01234567".to_string()
+        )])
+    );
+    assert_eq!(
+        metadata.issn,
+        Some(vec!["0000-0001".to_string(), "0000-0002".to_string()])
+    );
+    assert_eq!(iso8601(&metadata.created).as_deref(), Some("2023-05-02"));
+    assert_eq!(metadata.referenced_by_count, Some(42));
+}
+
+#[test]
+fn fixture_open_access() {
+    let metadata = metadata("open-access");
+    assert!(metadata.is_open_access());
+    assert_eq!(metadata.publisher.as_deref(), Some("MDPI AG"));
+}
+
+#[test]
+fn fixture_references() {
+    let metadata = metadata("references");
+    assert_eq!(metadata.references.as_ref().map(Vec::len), Some(3));
+    assert_eq!(metadata.referenced_dois().len(), 2);
+    assert_eq!(metadata.referenced_by_count, Some(1523));
+}
+
+#[test]
+fn fixture_subjects() {
+    let article = metadata("article");
+    assert_eq!(
+        article.subjects,
+        Some(vec![
+            "Electrical and Electronic Engineering".to_string(),
+            "Hardware and Architecture".to_string(),
+        ])
+    );
+    assert!(article.has_subject("hardware and architecture"));
+    assert!(!article.has_subject("Hardware"));
+    #[cfg(feature = "formats")]
+    assert!(article.to_ris().contains(
+        "KW  - Electrical and Electronic Engineering\nKW  - Hardware and Architecture\n"
+    ));
+
+    let conference = metadata("conference");
+    assert_eq!(conference.subjects, None);
+    assert!(!conference.has_subject("Hardware and Architecture"));
+}
+
+#[test]
+fn fixture_language() {
+    let german = metadata("german-article");
+    assert_eq!(german.language.as_deref(), Some("de"));
+    #[cfg(feature = "formats")]
+    {
+        assert_eq!(german.to_csl_json()["language"], "de");
+        assert!(german.to_ris().contains("LA  - de\n"));
+        assert!(german
+            .to_dublin_core_xml()
+            .contains("<dc:language>de</dc:language>"));
+    }
+
+    let article = metadata("article");
+    assert_eq!(article.language, None);
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{MockResponse, MockServer};
+use doi::{DoiBuilder, Resolver};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+/// Wakes the thread blocked in [`block_on`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs a future to completion on the current thread (a minimal executor).
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn resolve_async_matches_blocking() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/async" => MockResponse::redirect(302, "/landing").delay(Duration::from_millis(50)),
+        "/landing" => MockResponse::status(200),
+        _ => MockResponse::status(404),
+    });
+    let doi = server.doi("10.1/async");
+    let resolved = block_on(doi.resolve_async()).unwrap();
+    assert_eq!(resolved, doi.resolve().unwrap());
+    assert_eq!(resolved, format!("{}/landing", server.url()));
+    let error = block_on(server.doi("10.1/missing").resolve_async()).unwrap_err();
+    assert!(matches!(error, doi::DoiError::NotFound));
+}
+
+#[test]
+fn resolve_async_panic() {
+    let doi = DoiBuilder::new()
+        .doi("10.1/panic")
+        .env_proxy(false)
+        .resolver(Resolver::custom(|_| panic!("resolver panicked")))
+        .build();
+    // The future completes with an error instead of pending forever.
+    let error = block_on(doi.resolve_async()).unwrap_err();
+    assert!(error.to_string().contains("panicked"));
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn metadata_async_concurrent() {
+    let server = MockServer::start(|request| {
+        MockResponse::json(&format!(r#"{{"title": "{}"}}"#, request.path))
+            .delay(Duration::from_millis(200))
+    });
+    let futures: Vec<_> = (0..4)
+        .map(|i| server.doi(&format!("10.1/{}", i)).metadata_async())
+        .collect();
+    let start = std::time::Instant::now();
+    let titles: Vec<_> = futures
+        .into_iter()
+        .map(|future| block_on(future).unwrap().title.unwrap())
+        .collect();
+    assert_eq!(titles, ["/10.1/0", "/10.1/1", "/10.1/2", "/10.1/3"]);
+    // The requests run concurrently on their own threads.
+    assert!(start.elapsed() < Duration::from_millis(700));
+}
@@ -0,0 +1,1063 @@
+#![cfg(feature = "metadata")]
+
+mod common;
+
+use common::{MockResponse, MockServer};
+use doi::{DoiError, DoiMetadataDate, JsonValue, MetadataFormat};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::Write;
+use std::time::Duration;
+
+#[test]
+fn metadata_json_undeclared_gzip() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(br#"{"title": "Gzipped without Content-Encoding"}"#)
+        .unwrap();
+    let gzipped = encoder.finish().unwrap();
+    let server = MockServer::start(move |_| {
+        MockResponse::status(200)
+            .header("Content-Type", "application/json")
+            .body(gzipped.clone())
+    });
+    let json = server.doi("10.1/gzip").metadata_json().unwrap();
+    assert_eq!(json["title"], "Gzipped without Content-Encoding");
+}
+
+#[test]
+fn metadata_scalar_and_array_shapes() {
+    let server = MockServer::start(|request| {
+        if request.path.ends_with("scalar") {
+            MockResponse::json(r#"{"title": "Scalar Title", "container-title": "Scalar Journal"}"#)
+        } else {
+            MockResponse::json(
+                r#"{"title": ["Array Title"], "container-title": ["Array Journal", "Other"]}"#,
+            )
+        }
+    });
+    let scalar = server.doi("10.1/scalar").metadata().unwrap();
+    assert_eq!(scalar.doi, "10.1/scalar");
+    assert_eq!(scalar.title.as_deref(), Some("Scalar Title"));
+    assert_eq!(scalar.container_title.as_deref(), Some("Scalar Journal"));
+    let array = server.doi("10.1/array").metadata().unwrap();
+    assert_eq!(array.title.as_deref(), Some("Array Title"));
+    assert_eq!(array.container_title.as_deref(), Some("Array Journal"));
+}
+
+#[test]
+fn metadata_subtype() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(r#"{"type": "article-journal", "subtype": "correction"}"#)
+    });
+    let metadata = server.doi("10.1/subtype").metadata().unwrap();
+    assert_eq!(metadata.subtype.as_deref(), Some("correction"));
+    assert!(metadata.is_correction());
+}
+
+#[test]
+fn resolve_and_metadata_both_populated() {
+    let server = MockServer::start(|request| match request.method.as_str() {
+        "HEAD" => MockResponse::status(200),
+        _ => MockResponse::json(r#"{"title": "Combined"}"#),
+    });
+    let (link, metadata) = server.doi("10.1/combined").resolve_and_metadata().unwrap();
+    assert_eq!(link, format!("{}/10.1/combined", server.url()));
+    assert_eq!(metadata.title.as_deref(), Some("Combined"));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn metadata_clinical_trials() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"clinical-trial-number": [
+                {"clinical-trial-number": "NCT01234567", "registry": "10.18810/clinical-trials-gov", "type": "preResults"},
+                {"clinical-trial-number": "ISRCTN12345678", "registry": "10.18810/isrctn"},
+                {"registry": "10.18810/isrctn"}
+            ]}"#,
+        )
+    });
+    let metadata = server.doi("10.1/trial").metadata().unwrap();
+    assert_eq!(
+        metadata.clinical_trials,
+        Some(vec![
+            (
+                "10.18810/clinical-trials-gov".to_string(),
+                "NCT01234567".to_string()
+            ),
+            ("10.18810/isrctn".to_string(), "ISRCTN12345678".to_string()),
+        ])
+    );
+    let plain = MockServer::start(|_| MockResponse::json(r#"{"title": "No Trials"}"#));
+    assert_eq!(
+        plain.doi("10.1/plain").metadata().unwrap().clinical_trials,
+        None
+    );
+}
+
+#[test]
+fn metadata_published_other_only() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"title": "Other Date", "published-other": {"date-parts": [[2021, 7]]}}"#,
+        )
+    });
+    let metadata = server.doi("10.1/other").metadata().unwrap();
+    assert_eq!(
+        metadata.published_other,
+        Some(DoiMetadataDate {
+            year: Some(2021),
+            month: Some(7),
+            day: None,
+        })
+    );
+    assert_eq!(metadata.year(), Some(2021));
+}
+
+#[test]
+fn metadata_with_scrape_meta_tags() {
+    let server = MockServer::start(|request| {
+        if request.header("Accept") == Some("text/html") {
+            MockResponse::status(200)
+                .header("Content-Type", "text/html")
+                .body(concat!(
+                    "<html><head>\n",
+                    "<META NAME=\"citation_title\" CONTENT=\"Scraped &amp; Recovered\">\n",
+                    "<meta name=\"citation_author\" content=\"Jerry, Teddy\" />\n",
+                    "<meta content='Tom Cat' name='citation_author'>\n",
+                    "<meta name=\"citation_publication_date\" content=\"2023/05/17\">\n",
+                    "<meta name=\"DC.title\" content=\"Ignored\">\n",
+                    "</head></html>",
+                ))
+        } else {
+            MockResponse::json("{}")
+        }
+    });
+    let doi = server.doi("10.1/scrape");
+    assert!(doi.metadata().unwrap().title.is_none());
+    let metadata = doi.metadata_with_scrape().unwrap();
+    assert_eq!(metadata.doi, "10.1/scrape");
+    assert_eq!(metadata.title.as_deref(), Some("Scraped & Recovered"));
+    let authors = metadata.authors.unwrap();
+    assert_eq!(authors.len(), 2);
+    assert_eq!(authors[0].family.as_deref(), Some("Jerry"));
+    assert_eq!(authors[0].given.as_deref(), Some("Teddy"));
+    assert_eq!(authors[1].full_name().unwrap(), "Tom Cat");
+    assert_eq!(
+        metadata.published,
+        Some(DoiMetadataDate {
+            year: Some(2023),
+            month: Some(5),
+            day: Some(17),
+        })
+    );
+}
+
+#[test]
+fn metadata_with_scrape_prefers_negotiated() {
+    let server = MockServer::start(|_| MockResponse::json(r#"{"title": "Negotiated"}"#));
+    let metadata = server
+        .doi("10.1/negotiated")
+        .metadata_with_scrape()
+        .unwrap();
+    assert_eq!(metadata.title.as_deref(), Some("Negotiated"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn metadata_indexed_date() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"indexed": {"date-parts": [[2024, 3, 9]], "date-time": "2024-03-09T04:12:31Z", "timestamp": 1709957551000}}"#,
+        )
+    });
+    let metadata = server.doi("10.1/indexed").metadata().unwrap();
+    assert_eq!(
+        metadata.indexed,
+        Some(DoiMetadataDate {
+            year: Some(2024),
+            month: Some(3),
+            day: Some(9),
+        })
+    );
+    // The indexed date is not a publication date.
+    assert_eq!(metadata.year(), None);
+}
+
+#[test]
+fn metadata_organizational_author_sequence() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"author": [
+                {"name": "IEEE CAS Society", "sequence": "first", "affiliation": [{"name": "IEEE"}]},
+                {"given": "Teddy", "family": "Jerry", "sequence": "additional", "affiliation": []}
+            ]}"#,
+        )
+    });
+    let authors = server.doi("10.1/org").metadata().unwrap().authors.unwrap();
+    assert_eq!(authors[0].literal.as_deref(), Some("IEEE CAS Society"));
+    assert_eq!(authors[0].sequence.as_deref(), Some("first"));
+    assert_eq!(authors[0].affiliation, Some(vec!["IEEE".to_string()]));
+    assert_eq!(authors[1].family.as_deref(), Some("Jerry"));
+    assert_eq!(authors[1].sequence.as_deref(), Some("additional"));
+    assert_eq!(authors[1].affiliation, None);
+}
+
+#[test]
+fn metadata_number_of_pages() {
+    let server = MockServer::start(|request| {
+        if request.path.ends_with("string") {
+            MockResponse::json(r#"{"type": "book", "number-of-pages": "320"}"#)
+        } else {
+            MockResponse::json(r#"{"type": "book", "number-of-pages": 48}"#)
+        }
+    });
+    let string = server.doi("10.1/string").metadata().unwrap();
+    assert_eq!(string.number_of_pages, Some(320));
+    let number = server.doi("10.1/number").metadata().unwrap();
+    assert_eq!(number.number_of_pages, Some(48));
+}
+
+#[test]
+fn metadata_single_author_object() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(r#"{"author": {"given": "Teddy", "family": "Jerry"}}"#)
+    });
+    let authors = server
+        .doi("10.1/single")
+        .metadata()
+        .unwrap()
+        .authors
+        .unwrap();
+    assert_eq!(authors.len(), 1);
+    assert_eq!(authors[0].full_name().unwrap(), "Teddy Jerry");
+}
+
+#[test]
+fn metadata_group_title() {
+    let server = MockServer::start(|request| {
+        if request.path.ends_with("array") {
+            MockResponse::json(r#"{"group-title": ["Special Issue on HLS"]}"#)
+        } else {
+            MockResponse::json(r#"{"group-title": "Machine Learning"}"#)
+        }
+    });
+    let array = server.doi("10.1/array").metadata().unwrap();
+    assert_eq!(array.group_title.as_deref(), Some("Special Issue on HLS"));
+    let scalar = server.doi("10.1/scalar").metadata().unwrap();
+    assert_eq!(scalar.group_title.as_deref(), Some("Machine Learning"));
+}
+
+#[test]
+fn latest_follows_newer_versions() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/v1" => MockResponse::json(
+            r#"{"relation": {"is-replaced-by": [{"id-type": "doi", "id": "10.1/v2"}]}}"#,
+        ),
+        "/10.1/v2" => MockResponse::json(
+            r#"{"updated-by": [{"DOI": "10.1/erratum", "type": "erratum"}, {"DOI": "10.1/v3", "type": "new_version"}]}"#,
+        ),
+        "/10.1/cycle" => MockResponse::json(
+            r#"{"relation": {"is-previous-version-of": [{"id-type": "doi", "id": "10.1/CYCLE"}]}}"#,
+        ),
+        _ => MockResponse::json(r#"{"update-to": [{"DOI": "10.1/v2", "type": "new_version"}]}"#),
+    });
+    let latest = server.doi("10.1/v1").latest().unwrap();
+    assert_eq!(latest.doi.as_deref(), Some("10.1/v3"));
+    assert_eq!(latest.latest().unwrap(), latest);
+    let cycle = server.doi("10.1/cycle").latest().unwrap();
+    assert_eq!(cycle.doi.as_deref(), Some("10.1/cycle"));
+}
+
+#[test]
+fn metadata_with_warnings_malformed_date() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"title": "Malformed", "indexed": {"date-parts": [[2024, "May", 1]]},
+                "published-other": {"date-parts": [["n.d."]]}, "number-of-pages": "12"}"#,
+        )
+    });
+    let (metadata, warnings) = server.doi("10.1/warn").metadata_with_warnings().unwrap();
+    assert_eq!(metadata.title.as_deref(), Some("Malformed"));
+    assert_eq!(metadata.published_other, None);
+    assert_eq!(metadata.number_of_pages, Some(12));
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("`published-other`"));
+    assert!(warnings[1].contains("`indexed`"));
+    assert!(warnings[1].contains("May"));
+
+    let server = MockServer::start(|_| MockResponse::json(r#"{"title": "Clean"}"#));
+    let (_, warnings) = server.doi("10.1/clean").metadata_with_warnings().unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn metadata_primary_url_prefers_pdf() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"license": [{"URL": "https://creativecommons.org/licenses/by/4.0/"}], "link": [
+                {"URL": "https://example.org/similarity.pdf", "content-type": "application/pdf", "content-version": "vor", "intended-application": "similarity-checking"},
+                {"URL": "https://example.org/fulltext.xml", "content-type": "text/xml", "content-version": "vor", "intended-application": "text-mining"},
+                {"URL": "https://example.org/accepted.pdf", "content-type": "application/pdf", "content-version": "am", "intended-application": "text-mining"},
+                {"URL": "https://example.org/oa.pdf", "content-type": "application/pdf", "content-version": "vor", "intended-application": "text-mining"},
+                {"content-type": "application/pdf"}
+            ]}"#,
+        )
+    });
+    let metadata = server.doi("10.1/oa").metadata().unwrap();
+    assert_eq!(metadata.links.as_ref().map(Vec::len), Some(4));
+    let link = &metadata.links.as_ref().unwrap()[1];
+    assert_eq!(link.content_type.as_deref(), Some("text/xml"));
+    assert_eq!(link.intended_application.as_deref(), Some("text-mining"));
+    assert_eq!(
+        metadata.primary_url().as_deref(),
+        Some("https://example.org/oa.pdf")
+    );
+
+    let server = MockServer::start(|_| MockResponse::json(r#"{"title": "No Links"}"#));
+    let metadata = server.doi("10.1/none").metadata().unwrap();
+    assert_eq!(
+        metadata.primary_url().as_deref(),
+        Some("https://doi.org/10.1/none")
+    );
+}
+
+#[test]
+fn metadata_primary_url_open_access() {
+    let server = MockServer::start(|request| {
+        let license = if request.path.ends_with("/oa") {
+            r#""license": [{"URL": "http://creativecommons.org/licenses/by/4.0/", "content-version": "vor"}],"#
+        } else {
+            r#""license": [{"URL": "https://www.elsevier.com/tdm/userlicense/1.0/", "content-version": "tdm"}],"#
+        };
+        MockResponse::json(&format!(
+            r#"{{{} "link": [{{"URL": "https://example.org/fulltext.pdf", "content-type": "application/pdf", "content-version": "vor", "intended-application": "text-mining"}}]}}"#,
+            license
+        ))
+    });
+    let metadata = server.doi("10.1/oa").metadata().unwrap();
+    assert!(metadata.is_open_access());
+    assert_eq!(
+        metadata.primary_url().as_deref(),
+        Some("https://example.org/fulltext.pdf")
+    );
+    let metadata = server.doi("10.1/closed").metadata().unwrap();
+    assert!(!metadata.is_open_access());
+    assert_eq!(
+        metadata.primary_url().as_deref(),
+        Some("https://doi.org/10.1/closed")
+    );
+}
+
+#[test]
+fn metadata_title_whitespace_and_duplicates() {
+    let server = MockServer::start(|request| {
+        if request.path.ends_with("spaces") {
+            MockResponse::json(r#"{"title": "  Flexible  High-Level\n   Synthesis\tLibrary  "}"#)
+        } else {
+            MockResponse::json(
+                r#"{"title": ["   ", "Duplicated  Title ", "Duplicated Title", "Duplicated  Title"]}"#,
+            )
+        }
+    });
+    let spaces = server.doi("10.1/spaces").metadata().unwrap();
+    assert_eq!(
+        spaces.title.as_deref(),
+        Some("Flexible High-Level Synthesis Library")
+    );
+    let duplicates = server.doi("10.1/duplicates").metadata().unwrap();
+    assert_eq!(duplicates.title.as_deref(), Some("Duplicated Title"));
+}
+
+#[test]
+fn metadata_preferred_format_accept() {
+    let server = MockServer::start(|request| {
+        let accept = request.header("Accept").unwrap_or_default();
+        MockResponse::json(&format!(r#"{{"title": "{}"}}"#, accept))
+    });
+    let csl = server
+        .builder("10.1/csl")
+        .prefer_format(MetadataFormat::CslJson)
+        .build()
+        .metadata_preferred()
+        .unwrap();
+    assert_eq!(
+        csl.title.as_deref(),
+        Some("application/vnd.citationstyles.csl+json")
+    );
+    let json = server.doi("10.1/json").metadata_preferred().unwrap();
+    assert_eq!(json.title.as_deref(), Some("application/json"));
+    let accepts: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|r| r.header("Accept").unwrap_or_default().to_string())
+        .collect();
+    assert_eq!(
+        accepts,
+        [
+            "application/vnd.citationstyles.csl+json",
+            "application/json"
+        ]
+    );
+}
+
+#[test]
+fn metadata_date_parts_and_iso_string() {
+    let server = MockServer::start(|request| {
+        MockResponse::json(match request.path.rsplit('/').next().unwrap() {
+            "parts" => r#"{"published-other": {"date-parts": [[2024, 2, 14]]}}"#,
+            "iso" => r#"{"published-other": "2024-02-14"}"#,
+            "raw" => r#"{"published-other": {"raw": "2024-02-14"}}"#,
+            _ => {
+                r#"{"published-other": {"date-parts": [[null]], "date-time": "2024-02-14T08:30:00Z"}}"#
+            }
+        })
+    });
+    let expected = Some(DoiMetadataDate {
+        year: Some(2024),
+        month: Some(2),
+        day: Some(14),
+    });
+    for path in ["parts", "iso", "raw", "date-time"] {
+        let (metadata, warnings) = server
+            .doi(&format!("10.1/{}", path))
+            .metadata_with_warnings()
+            .unwrap();
+        assert_eq!(metadata.published_other, expected, "{}", path);
+        assert_eq!(metadata.year(), Some(2024), "{}", path);
+        assert_eq!(warnings.len(), usize::from(path == "date-time"), "{}", path);
+    }
+
+    let server = MockServer::start(|_| MockResponse::json(r#"{"indexed": "2024-03"}"#));
+    let metadata = server.doi("10.1/partial").metadata().unwrap();
+    assert_eq!(
+        metadata.indexed,
+        Some(DoiMetadataDate {
+            year: Some(2024),
+            month: Some(3),
+            day: None,
+        })
+    );
+}
+
+#[test]
+fn metadata_timeout() {
+    let server = MockServer::start(|_| MockResponse::json("{}").delay(Duration::from_secs(3)));
+    let doi = server
+        .builder("10.1/slow")
+        .timeout(Duration::from_millis(200))
+        .build();
+    assert!(matches!(doi.metadata(), Err(DoiError::Timeout(_))));
+    assert!(matches!(doi.metadata_json(), Err(DoiError::Timeout(_))));
+}
+
+#[test]
+fn metadata_lazy_memoized() {
+    let server = MockServer::start(|request| {
+        let title = request.path.trim_start_matches("/10.1/");
+        MockResponse::json(&format!(
+            r#"{{"title": "{}", "author": [{{"given": "Ada", "family": "Lovelace"}}]}}"#,
+            title
+        ))
+    });
+    let mut doi = server.doi("10.1/first");
+    assert_eq!(doi.title_lazy().unwrap().as_deref(), Some("first"));
+    assert_eq!(server.requests().len(), 1);
+    let authors = doi.authors_lazy().unwrap().unwrap();
+    assert_eq!(authors[0].family.as_deref(), Some("Lovelace"));
+    assert_eq!(doi.clone().title_lazy().unwrap().as_deref(), Some("first"));
+    assert_eq!(server.requests().len(), 1);
+    // Changing the DOI fetches the metadata again.
+    doi.set_doi("10.1/second");
+    assert_eq!(doi.title_lazy().unwrap().as_deref(), Some("second"));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn metadata_abstract_jats() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"abstract": "<jats:title>Abstract</jats:title>\n<jats:p>We propose a <jats:italic>novel</jats:italic> filter (&lt;1&#x00A0;mW) &amp; a   tuner.</jats:p>\n<jats:sec><jats:title>Results</jats:title><jats:p>Caf&#233; AT&T&gt;</jats:p></jats:sec>"}"#,
+        )
+    });
+    let metadata = server.doi("10.1/abstract").metadata().unwrap();
+    assert!(metadata
+        .r#abstract
+        .as_deref()
+        .unwrap()
+        .starts_with("<jats:title>"));
+    assert_eq!(
+        metadata.abstract_text().as_deref(),
+        Some("We propose a novel filter (<1 mW) & a tuner.\n\nResults\n\nCafé AT&T>")
+    );
+}
+
+#[test]
+fn metadata_type_predicates() {
+    let server = MockServer::start(|request| {
+        let r#type = request.path.trim_start_matches("/10.1/");
+        MockResponse::json(&format!(r#"{{"type": "{}"}}"#, r#type))
+    });
+    let predicates = |r#type: &str| {
+        let metadata = server.doi(&format!("10.1/{}", r#type)).metadata().unwrap();
+        (
+            metadata.is_journal_article(),
+            metadata.is_conference_paper(),
+            metadata.is_book(),
+        )
+    };
+    assert_eq!(predicates("article-journal"), (true, false, false));
+    assert_eq!(predicates("paper-conference"), (false, true, false));
+    assert_eq!(predicates("book"), (false, false, true));
+    assert_eq!(predicates("chapter"), (false, false, false));
+    assert_eq!(predicates("journal-issue"), (false, false, false));
+}
+
+#[test]
+fn references_dois() {
+    let server = MockServer::start(|request| {
+        if request.path.ends_with("none") {
+            return MockResponse::json(r#"{"title": "No References", "reference": null}"#);
+        }
+        MockResponse::json(
+            r#"{
+                "title": "Highly Cited",
+                "author": [{"given": "Ada", "family": "Lovelace"}],
+                "reference": [
+                    {"key": "ref1", "DOI": "10.1109/TCSII.2024.3366282", "doi-asserted-by": "crossref"},
+                    {"key": "ref2", "unstructured": "A. Author, Some Book, 1999."},
+                    {"key": "ref3", "DOI": "10.1145/3643832.3661865", "year": "2024"},
+                    {"key": "ref4", "DOI": 42},
+                    null,
+                    "10.1/not-an-object",
+                    [{"DOI": "10.1/nested"}]
+                ],
+                "references-count": 4
+            }"#,
+        )
+    });
+    assert_eq!(
+        server.doi("10.1/cited").references().unwrap(),
+        ["10.1109/TCSII.2024.3366282", "10.1145/3643832.3661865"]
+    );
+    assert!(server.doi("10.1/none").references().unwrap().is_empty());
+}
+
+#[test]
+fn metadata_published_issued() {
+    let server = MockServer::start(|request| {
+        MockResponse::json(match request.path.as_str() {
+            "/10.1/year" => r#"{"issued": {"date-parts": [[2024]]}}"#,
+            "/10.1/month" => r#"{"issued": {"date-parts": [[2024, 2]]}}"#,
+            "/10.1/full" => {
+                r#"{"issued": {"date-parts": [[2024, 2, 15]]}, "published-other": {"date-parts": [[2023]]}}"#
+            }
+            "/10.1/fallback" => r#"{"published": {"date-parts": [[2022, 12, 1]]}}"#,
+            _ => r#"{"issued": {"date-parts": [[null]]}}"#,
+        })
+    });
+    let published = |doi: &str| server.doi(doi).metadata().unwrap().published;
+    let year = published("10.1/year").unwrap();
+    assert_eq!((year.year, year.month, year.day), (Some(2024), None, None));
+    assert_eq!(year.to_iso8601().as_deref(), Some("2024"));
+    let month = published("10.1/month").unwrap();
+    assert_eq!(month.to_iso8601().as_deref(), Some("2024-02"));
+    let full = server.doi("10.1/full").metadata().unwrap();
+    assert_eq!(
+        full.published
+            .as_ref()
+            .and_then(DoiMetadataDate::to_iso8601)
+            .as_deref(),
+        Some("2024-02-15")
+    );
+    assert_eq!(full.year(), Some(2024));
+    assert_eq!(
+        published("10.1/fallback")
+            .and_then(|date| date.to_iso8601())
+            .as_deref(),
+        Some("2022-12-01")
+    );
+    // An empty date is dropped with a warning.
+    let (metadata, warnings) = server.doi("10.1/empty").metadata_with_warnings().unwrap();
+    assert_eq!(metadata.published, None);
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+}
+
+#[test]
+fn metadata_container_title_fixtures() {
+    let server = MockServer::start(|request| {
+        MockResponse::json(if request.path.contains("3661865") {
+            include_str!("fixtures/conference.json")
+        } else {
+            include_str!("fixtures/article.json")
+        })
+    });
+    let article = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(
+        article.container_title.as_deref(),
+        Some("IEEE Transactions on Circuits and Systems II: Express Briefs")
+    );
+    assert_eq!(
+        article.short_container_title.as_deref(),
+        Some("IEEE Trans. Circuits Syst. II")
+    );
+    let paper = server.doi("10.1145/3643832.3661865").metadata().unwrap();
+    assert_eq!(
+        paper.container_title.as_deref(),
+        Some("Proceedings of the 22nd Annual International Conference on Mobile Systems, Applications and Services")
+    );
+    assert_eq!(paper.short_container_title, None);
+}
+
+#[test]
+fn metadata_mononym_authors() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"author": [
+                {"given": "Plato", "sequence": "first"},
+                {"family": "Aristotle"},
+                {"name": "Socrates"},
+                {"given": "Homer", "family": ""},
+                {"given": " ", "family": "Sappho "},
+                {"literal": "", "name": "Euclid"}
+            ]}"#,
+        )
+    });
+    let authors = server
+        .doi("10.1/mononym")
+        .metadata()
+        .unwrap()
+        .authors
+        .unwrap();
+    let names: Vec<_> = authors.iter().map(|a| a.full_name().unwrap()).collect();
+    assert_eq!(
+        names,
+        [
+            "Plato",
+            "Aristotle",
+            "Socrates",
+            "Homer",
+            "Sappho",
+            "Euclid"
+        ]
+    );
+    let family_given: Vec<_> = authors.iter().map(|a| a.family_given().unwrap()).collect();
+    assert_eq!(family_given, names);
+    assert_eq!(authors[3].family, None);
+    assert_eq!(authors[5].literal.as_deref(), Some("Euclid"));
+}
+
+#[test]
+fn metadata_volume_issue_page() {
+    let server = MockServer::start(|request| {
+        MockResponse::json(match request.path.as_str() {
+            "/10.1/single" => r#"{"volume": 12, "issue": "3", "page": "42"}"#,
+            "/10.1/electronic" => r#"{"volume": "7", "page": "e12345"}"#,
+            _ => include_str!("fixtures/article.json"),
+        })
+    });
+    let article = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(article.volume.as_deref(), Some("71"));
+    assert_eq!(article.issue.as_deref(), Some("7"));
+    assert_eq!(article.page.as_deref(), Some("3303-3307"));
+    assert_eq!(
+        article.page_range(),
+        Some(("3303".to_string(), "3307".to_string()))
+    );
+    let single = server.doi("10.1/single").metadata().unwrap();
+    assert_eq!(single.volume.as_deref(), Some("12"));
+    assert_eq!(single.page.as_deref(), Some("42"));
+    assert_eq!(single.page_range(), None);
+    let electronic = server.doi("10.1/electronic").metadata().unwrap();
+    assert_eq!(electronic.issue, None);
+    assert_eq!(electronic.page.as_deref(), Some("e12345"));
+    assert_eq!(electronic.page_range(), None);
+}
+
+#[cfg(feature = "formats")]
+#[test]
+fn formats_volume_issue_page() {
+    let mut metadata = doi::DoiMetadata::new("10.1/x".to_string());
+    metadata.volume = Some("71".to_string());
+    metadata.issue = Some("7".to_string());
+    metadata.page = Some("3303-3307".to_string());
+    let ris = metadata.to_ris();
+    assert!(
+        ris.contains("VL  - 71\nIS  - 7\nSP  - 3303\nEP  - 3307\n"),
+        "{}",
+        ris
+    );
+    let openurl = metadata.to_openurl();
+    assert!(openurl.contains("&rft.volume=71&rft.issue=7&rft.spage=3303&rft.epage=3307"));
+    let csl = metadata.to_csl_json();
+    assert_eq!(csl["page"], "3303-3307");
+    assert!(metadata
+        .to_endnote_xml()
+        .contains("<pages>3303-3307</pages><volume>71</volume><number>7</number>"));
+    metadata.page = Some("e12345".to_string());
+    assert!(metadata.to_ris().contains("SP  - e12345\nDO  - "));
+    assert!(metadata.to_openurl().contains("&rft.pages=e12345"));
+}
+
+#[test]
+fn metadata_publisher_fixture() {
+    let server = MockServer::start(|_| MockResponse::json(include_str!("fixtures/article.json")));
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(
+        metadata.publisher.as_deref(),
+        Some("Institute of Electrical and Electronics Engineers (IEEE)")
+    );
+    #[cfg(feature = "formats")]
+    {
+        assert!(metadata
+            .to_ris()
+            .contains("PB  - Institute of Electrical and Electronics Engineers (IEEE)\n"));
+        assert_eq!(
+            metadata.to_csl_json()["publisher"],
+            "Institute of Electrical and Electronics Engineers (IEEE)"
+        );
+        assert!(metadata.to_dublin_core_xml().contains(
+            "<dc:publisher>Institute of Electrical and Electronics Engineers (IEEE)</dc:publisher>"
+        ));
+    }
+}
+
+#[test]
+fn metadata_created_date() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{
+                "issued": {"date-parts": [[2024, 7]]},
+                "created": {"date-parts": [[2024, 2, 14]], "date-time": "2024-02-14T18:37:29Z", "timestamp": 1707935849000},
+                "indexed": {"date-parts": [[2024, 8, 1]]}
+            }"#,
+        )
+    });
+    let metadata = server.doi("10.1/created").metadata().unwrap();
+    assert_eq!(
+        metadata.created,
+        Some(DoiMetadataDate {
+            year: Some(2024),
+            month: Some(2),
+            day: Some(14),
+        })
+    );
+    // The publication date is kept separate.
+    assert_eq!(
+        metadata
+            .published
+            .as_ref()
+            .and_then(DoiMetadataDate::to_iso8601)
+            .as_deref(),
+        Some("2024-07")
+    );
+    assert_eq!(metadata.year(), Some(2024));
+}
+
+#[test]
+fn metadata_issn_fixture() {
+    let server = MockServer::start(|_| MockResponse::json(include_str!("fixtures/article.json")));
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(
+        metadata.issn,
+        Some(vec!["1549-7747".to_string(), "1558-3791".to_string()])
+    );
+    assert_eq!(metadata.primary_issn(), Some("1549-7747"));
+    #[cfg(feature = "formats")]
+    {
+        let ris = metadata.to_ris();
+        assert!(ris.contains("SN  - 1549-7747\nSN  - 1558-3791\n"));
+        assert!(metadata.to_openurl().contains("&rft.issn=1549-7747"));
+        assert_eq!(metadata.to_csl_json()["ISSN"][1], "1558-3791");
+        assert!(metadata.to_endnote_xml().contains("<isbn>1549-7747</isbn>"));
+    }
+}
+
+#[test]
+fn metadata_issn_type_and_missing() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("journal") {
+            MockResponse::json(
+                r#"{"issn-type": [{"type": "print", "value": "1549-7747"}, {"type": "electronic", "value": "1558-3791"}]}"#,
+            )
+        } else {
+            MockResponse::json(include_str!("fixtures/conference.json"))
+        }
+    });
+    let metadata = server.doi("10.1234/journal").metadata().unwrap();
+    assert_eq!(
+        metadata.issn,
+        Some(vec!["1549-7747".to_string(), "1558-3791".to_string()])
+    );
+    let metadata = server.doi("10.1145/3643832.3661865").metadata().unwrap();
+    assert_eq!(metadata.issn, None);
+    assert_eq!(metadata.primary_issn(), None);
+}
+
+#[test]
+fn metadata_isbn() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("chapter") {
+            MockResponse::json(
+                r#"{"type": "book-chapter", "ISBN": ["http://id.crossref.org/isbn/9783030123456", "https://id.crossref.org/isbn/9783030123463"]}"#,
+            )
+        } else {
+            MockResponse::json(include_str!("fixtures/conference.json"))
+        }
+    });
+    let metadata = server.doi("10.1145/3643832.3661865").metadata().unwrap();
+    assert_eq!(metadata.isbn, Some(vec!["9798400705816".to_string()]));
+    #[cfg(feature = "formats")]
+    {
+        assert!(metadata.to_ris().contains("SN  - 9798400705816\n"));
+        assert!(metadata.to_openurl().contains("&rft.isbn=9798400705816"));
+        assert!(metadata
+            .to_endnote_xml()
+            .contains("<isbn>9798400705816</isbn>"));
+    }
+
+    let metadata = server.doi("10.1007/chapter").metadata().unwrap();
+    assert_eq!(
+        metadata.isbn,
+        Some(vec![
+            "9783030123456".to_string(),
+            "9783030123463".to_string()
+        ])
+    );
+    #[cfg(feature = "formats")]
+    assert_eq!(metadata.to_csl_json()["ISBN"][0], "9783030123456");
+}
+
+#[test]
+fn metadata_title_language() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("datacite") {
+            MockResponse::json(
+                r#"{
+                    "titles": [
+                        {"title": "Messdaten zur linearen Transformation", "lang": "de"},
+                        {"title": "Measurement Data", "titleType": "TranslatedTitle", "lang": "en"}
+                    ]
+                }"#,
+            )
+        } else {
+            MockResponse::json(include_str!("fixtures/article.json"))
+        }
+    });
+    let metadata = server.doi("10.5281/datacite").metadata().unwrap();
+    assert_eq!(
+        metadata.title.as_deref(),
+        Some("Messdaten zur linearen Transformation")
+    );
+    assert_eq!(metadata.title_language.as_deref(), Some("de"));
+
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert!(metadata.title.is_some());
+    assert_eq!(metadata.title_language, None);
+}
+
+#[test]
+fn metadata_abstract_fixture() {
+    let server = MockServer::start(|_| MockResponse::json(include_str!("fixtures/article.json")));
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(
+        metadata.r#abstract.as_deref(),
+        Some(concat!(
+            "<jats:p>This brief presents a flexible high-level synthesis (HLS) library for linear transformations.</jats:p>\n",
+            "<jats:p>The library supports the DFT &amp; the DCT.</jats:p>"
+        ))
+    );
+    assert_eq!(
+        metadata.abstract_text().as_deref(),
+        Some(concat!(
+            "This brief presents a flexible high-level synthesis (HLS) library for linear transformations.\n\n",
+            "The library supports the DFT & the DCT."
+        ))
+    );
+}
+
+#[test]
+fn metadata_license() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("electronics") {
+            MockResponse::json(include_str!("fixtures/open-access.json"))
+        } else {
+            MockResponse::json(include_str!("fixtures/article.json"))
+        }
+    });
+    let metadata = server
+        .doi("10.3390/electronics13010001")
+        .metadata()
+        .unwrap();
+    let license = &metadata.license.as_ref().unwrap()[0];
+    assert_eq!(license.url, "https://creativecommons.org/licenses/by/4.0/");
+    assert_eq!(license.content_version.as_deref(), Some("vor"));
+    assert_eq!(
+        license
+            .start
+            .as_ref()
+            .and_then(DoiMetadataDate::to_iso8601)
+            .as_deref(),
+        Some("2023-12-19")
+    );
+    assert!(metadata.is_open_access());
+
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert!(metadata.license.is_none());
+    assert!(!metadata.is_open_access());
+}
+
+#[test]
+fn metadata_merge_from_json() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{"DOI": "10.1109/TCSII.2024.3366282", "title": "Flexible HLS Library"}"#,
+        )
+    });
+    let mut metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(metadata.publisher, None);
+    let json: JsonValue =
+        ureq::serde_json::from_str(include_str!("fixtures/article.json")).unwrap();
+    metadata.merge_from_json(&json);
+    assert_eq!(
+        metadata.publisher.as_deref(),
+        Some("Institute of Electrical and Electronics Engineers (IEEE)")
+    );
+    assert_eq!(metadata.volume.as_deref(), Some("71"));
+    // Fields that are already set are kept.
+    assert_eq!(metadata.title.as_deref(), Some("Flexible HLS Library"));
+    assert_eq!(metadata.doi, "10.1109/TCSII.2024.3366282");
+}
+
+#[test]
+fn metadata_primary_url_registered() {
+    let server = MockServer::start(|request| {
+        let url = if request.path.ends_with("/publisher") {
+            "https://publisher.example.org/article/1"
+        } else {
+            "https://doi.org/10.1/crossref"
+        };
+        MockResponse::json(&format!(r#"{{"URL": "{}"}}"#, url))
+    });
+    let metadata = server.doi("10.1/publisher").metadata().unwrap();
+    assert_eq!(
+        metadata.primary_url().as_deref(),
+        Some("https://publisher.example.org/article/1")
+    );
+    // A registered DOI URL (lowercased by Crossref) is not preferred over the DOI URL.
+    let metadata = server.doi("10.1/Crossref").metadata().unwrap();
+    assert_eq!(
+        metadata.primary_url().as_deref(),
+        Some("https://doi.org/10.1/Crossref")
+    );
+}
+
+#[test]
+fn metadata_url_differs_from_resolved() {
+    let server = MockServer::start(|request| {
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/10.1/registered") => MockResponse::json(
+                r#"{"DOI": "10.1/registered", "URL": "https://publisher.example.org/article/1"}"#,
+            ),
+            (_, "/10.1/registered") => MockResponse::redirect(302, "/article/1"),
+            (_, "/article/1") => MockResponse::redirect(301, "/en/article/1"),
+            _ => MockResponse::status(200),
+        }
+    });
+    let doi = server.doi("10.1/registered");
+    let metadata = doi.metadata().unwrap();
+    assert_eq!(
+        metadata.url.as_deref(),
+        Some("https://publisher.example.org/article/1")
+    );
+    assert_eq!(
+        doi.resolve().unwrap(),
+        format!("{}/en/article/1", server.url())
+    );
+    #[cfg(feature = "formats")]
+    assert_eq!(
+        metadata.to_csl_json()["URL"],
+        "https://publisher.example.org/article/1"
+    );
+}
+
+#[test]
+fn metadata_references_fixture() {
+    let server =
+        MockServer::start(|_| MockResponse::json(include_str!("fixtures/references.json")));
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    let references = metadata.references.as_ref().unwrap();
+    assert_eq!(references.len(), 3);
+    assert_eq!(references[0].key.as_deref(), Some("ref1"));
+    assert_eq!(references[0].unstructured, None);
+    assert_eq!(references[1].doi, None);
+    assert!(references[1]
+        .unstructured
+        .as_deref()
+        .unwrap()
+        .starts_with("J. Doe"));
+    assert_eq!(
+        references[2].doi.as_deref(),
+        Some("10.1145/3385412.3386006")
+    );
+    let dois: Vec<_> = metadata
+        .referenced_dois()
+        .iter()
+        .map(|doi| doi.to_string())
+        .collect();
+    assert_eq!(
+        dois,
+        ["10.1109/TCAD.2020.3012345", "10.1145/3385412.3386006"]
+    );
+}
+
+#[test]
+fn metadata_abstracts_by_language() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            r#"{
+                "descriptions": [
+                    {"description": "Measurement data of linear transformations.", "descriptionType": "Abstract", "lang": "en"},
+                    {"description": "Messdaten linearer Transformationen.", "descriptionType": "Abstract", "lang": "de"},
+                    {"description": "Version 2 fixes the units.", "descriptionType": "TechnicalInfo"}
+                ]
+            }"#,
+        )
+    });
+    let metadata = server.doi("10.5281/zenodo.1234567").metadata().unwrap();
+    assert_eq!(
+        metadata.abstracts,
+        Some(vec![
+            (
+                Some("en".to_string()),
+                "Measurement data of linear transformations.".to_string()
+            ),
+            (
+                Some("de".to_string()),
+                "Messdaten linearer Transformationen.".to_string()
+            ),
+        ])
+    );
+    assert_eq!(
+        metadata.r#abstract.as_deref(),
+        Some("Measurement data of linear transformations.")
+    );
+}
+
+#[test]
+fn metadata_referenced_by_count() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1109/TCSII.2024.3366282" => {
+            MockResponse::json(include_str!("fixtures/references.json"))
+        }
+        "/10.1/invalid" => MockResponse::json(r#"{"is-referenced-by-count": "many"}"#),
+        _ => MockResponse::json(include_str!("fixtures/conference.json")),
+    });
+    let metadata = server.doi("10.1109/TCSII.2024.3366282").metadata().unwrap();
+    assert_eq!(metadata.referenced_by_count, Some(1523));
+    let metadata = server.doi("10.1145/3643832.3661865").metadata().unwrap();
+    assert_eq!(metadata.referenced_by_count, None);
+    let metadata = server.doi("10.1/invalid").metadata().unwrap();
+    assert_eq!(metadata.referenced_by_count, None);
+}
@@ -0,0 +1,640 @@
+mod common;
+
+use common::{MockRequest, MockResponse, MockServer};
+use doi::{Doi, DoiBuilder, DoiError, RedirectKind, ResolveErrorKind, Resolver};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+#[test]
+fn resolve_sends_configured_accept() {
+    let server = MockServer::start(|_| MockResponse::status(200));
+    let doi = server
+        .builder("10.1/accept")
+        .resolve_accept("text/html")
+        .build();
+    doi.resolve().unwrap();
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "HEAD");
+    assert_eq!(requests[0].header("Accept"), Some("text/html"));
+}
+
+#[test]
+fn resolve_sends_default_accept() {
+    let server = MockServer::start(|_| MockResponse::status(200));
+    server.doi("10.1/accept").resolve().unwrap();
+    assert_eq!(server.requests()[0].header("Accept"), Some("*/*"));
+}
+
+/// The mock acts as an HTTP proxy, so it also answers for the (unresolvable) landing host.
+#[cfg(feature = "proxy")]
+#[test]
+fn landing_domain_idn_punycode() {
+    let server = MockServer::start(|request| {
+        if request.path.starts_with("http://doi.test/") {
+            MockResponse::redirect(302, "http://b%C3%BCcher.example/paper")
+        } else {
+            MockResponse::status(200)
+        }
+    });
+    let doi = server
+        .builder("10.1/idn")
+        .resolver(doi::Resolver::BaseUrl("http://doi.test".to_string()))
+        .proxy(server.url())
+        .unwrap()
+        .build();
+    assert_eq!(doi.landing_domain().unwrap(), "xn--bcher-kva.example");
+    assert_eq!(
+        server.requests()[1].path,
+        "http://xn--bcher-kva.example/paper"
+    );
+}
+
+#[test]
+fn rate_limit_spaces_requests() {
+    let server = MockServer::start(|_| MockResponse::status(200));
+    let mut builder = server.builder("10.1/rate-limit");
+    builder.rate_limit(5.0);
+    let doi1 = builder.build();
+    let doi2 = builder.doi("10.1/rate-limit-2").build();
+    doi1.resolve().unwrap();
+    let start = std::time::Instant::now();
+    doi2.resolve().unwrap();
+    assert!(start.elapsed() >= std::time::Duration::from_millis(180));
+}
+
+#[test]
+fn resolve_canonical_from_link_header() {
+    let server = MockServer::start(|_| {
+        MockResponse::status(200).header(
+            "Link",
+            r#"<https://example.com/style.css>; rel="stylesheet", <https://doi.org/10.1/Primary>; rel="canonical""#,
+        )
+    });
+    let resolution = server.doi("10.1/alias").resolve_canonical().unwrap();
+    assert_eq!(resolution.url, format!("{}/10.1/alias", server.url()));
+    assert_eq!(resolution.canonical_doi.as_deref(), Some("10.1/Primary"));
+}
+
+#[test]
+fn resolve_canonical_from_x_doi_header() {
+    let server = MockServer::start(|_| MockResponse::status(200).header("X-DOI", "10.1/primary"));
+    let resolution = server.doi("10.1/alias").resolve_canonical().unwrap();
+    assert_eq!(resolution.canonical_doi.as_deref(), Some("10.1/primary"));
+    let plain = MockServer::start(|_| MockResponse::status(200));
+    assert_eq!(
+        plain
+            .doi("10.1/x")
+            .resolve_canonical()
+            .unwrap()
+            .canonical_doi,
+        None
+    );
+}
+
+#[test]
+fn resolve_error_kind_status() {
+    let server = MockServer::start(|_| MockResponse::status(503));
+    let error = server.doi("10.1/unavailable").resolve().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Status(503)));
+}
+
+#[test]
+fn resolve_error_not_found() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("dead") {
+            MockResponse::redirect(302, "/landing/gone")
+        } else {
+            MockResponse::status(404)
+        }
+    });
+    let error = server.doi("10.1/unknown").resolve().unwrap_err();
+    assert!(matches!(error, DoiError::NotFound));
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Status(404)));
+    // A dead landing page is not an unknown DOI.
+    let error = server.doi("10.1/dead").resolve().unwrap_err();
+    assert!(matches!(&error, DoiError::Http(e) if matches!(**e, ureq::Error::Status(404, _))));
+    assert!(matches!(Doi::default().resolve(), Err(DoiError::NotSet)));
+}
+
+#[test]
+fn resolve_error_kind_timeout() {
+    let server = MockServer::start(|_| MockResponse::status(200).delay(Duration::from_secs(2)));
+    let agent = ureq::AgentBuilder::new()
+        .timeout_read(Duration::from_millis(100))
+        .build();
+    let error = agent.head(&server.url()).call().unwrap_err();
+    assert_eq!(ResolveErrorKind::from(&error), ResolveErrorKind::Timeout);
+}
+
+#[test]
+fn resolve_error_kind_connection() {
+    // Bind and drop a listener to get a local port that refuses connections.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let doi = DoiBuilder::new()
+        .doi("10.1/refused")
+        .env_proxy(false)
+        .resolver(Resolver::BaseUrl(format!("http://127.0.0.1:{}", port)))
+        .build();
+    let error = doi.resolve().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Connection));
+}
+
+#[test]
+fn timeout_read_slow_response() {
+    let server = MockServer::start(|_| MockResponse::status(200).delay(Duration::from_secs(3)));
+    let doi = server
+        .builder("10.1/slow")
+        .timeout_read(Duration::from_millis(200))
+        .build();
+    let start = Instant::now();
+    let error = doi.resolve().unwrap_err();
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(matches!(error, DoiError::Timeout(_)));
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Timeout));
+}
+
+#[test]
+fn timeout_overall_slow_response() {
+    let server = MockServer::start(|_| MockResponse::status(200).delay(Duration::from_secs(3)));
+    let doi = server
+        .builder("10.1/slow")
+        .timeout(Duration::from_millis(200))
+        .build();
+    let error = doi.resolve().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Timeout));
+}
+
+#[test]
+fn timeout_connect_unroutable_host() {
+    // A non-routable address never answers the connection attempt
+    // (or is rejected right away without a network).
+    let doi = DoiBuilder::new()
+        .doi("10.1/unroutable")
+        .env_proxy(false)
+        .resolver(Resolver::BaseUrl("http://10.255.255.1".to_string()))
+        .timeout_connect(Duration::from_millis(200))
+        .build();
+    let start = Instant::now();
+    assert!(doi.resolve().is_err());
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn resolve_traced_two_redirects() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/traced" => MockResponse::redirect(302, "/hop"),
+        "/hop" => MockResponse::redirect(301, "/landing"),
+        _ => MockResponse::status(200),
+    });
+    let trace = server.doi("10.1/traced").resolve_traced().unwrap();
+    let hops: Vec<_> = trace
+        .hops
+        .iter()
+        .map(|hop| (hop.url.trim_start_matches(&server.url()), hop.status))
+        .collect();
+    assert_eq!(
+        hops,
+        [("/10.1/traced", 302), ("/hop", 301), ("/landing", 200)]
+    );
+}
+
+#[test]
+fn resolve_redirect_kind() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/moved" => MockResponse::redirect(301, "/hop"),
+        "/10.1/found" => MockResponse::redirect(302, "/hop"),
+        "/hop" => MockResponse::redirect(302, "/landing"),
+        _ => MockResponse::status(200),
+    });
+    let (url, kind) = server.doi("10.1/moved").resolve_redirect_kind().unwrap();
+    assert_eq!(url, format!("{}/landing", server.url()));
+    assert_eq!(kind, RedirectKind::Permanent);
+    let (_, kind) = server.doi("10.1/found").resolve_redirect_kind().unwrap();
+    assert_eq!(kind, RedirectKind::Temporary);
+    let (_, kind) = server.doi("10.1/direct").resolve_redirect_kind().unwrap();
+    assert_eq!(kind, RedirectKind::None);
+}
+
+/// The mock acts as an HTTP proxy requiring no authentication.
+#[cfg(feature = "proxy")]
+#[test]
+fn proxy_with_credentials() {
+    let server = MockServer::start(|_| MockResponse::status(200));
+    let address = server.url().replace("http://", "");
+    let doi = server
+        .builder("10.1/auth")
+        .resolver(Resolver::BaseUrl("http://doi.test".to_string()))
+        .proxy(format!("http://user:secret@{}", address))
+        .unwrap()
+        .build();
+    assert_eq!(doi.resolve().unwrap(), "http://doi.test/10.1/auth");
+    let doi = server
+        .builder("10.1/auth")
+        .resolver(Resolver::BaseUrl("http://doi.test".to_string()))
+        .proxy(server.url())
+        .unwrap()
+        .proxy_auth("user", "p@ss:word")
+        .unwrap()
+        .build();
+    assert_eq!(doi.resolve().unwrap(), "http://doi.test/10.1/auth");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn deadline_across_redirect_hops() {
+    // Each hop is fast enough for a per-request timeout, but the chain is not.
+    let server = MockServer::start(|request| {
+        let n: u32 = request.path.trim_start_matches("/hop").parse().unwrap_or(0);
+        MockResponse::redirect(302, &format!("/hop{}", n + 1)).delay(Duration::from_millis(300))
+    });
+    let doi = server
+        .builder("10.1/deadline")
+        .hsts_upgrade(true)
+        .timeout(Duration::from_secs(1))
+        .deadline(Duration::from_millis(700))
+        .build();
+    let start = Instant::now();
+    let error = doi.resolve().unwrap_err();
+    assert!(start.elapsed() < Duration::from_millis(1200));
+    assert!(matches!(error, DoiError::Timeout(_)));
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Timeout));
+    assert!(server.requests().len() <= 3);
+}
+
+#[test]
+fn timeout_within_deadline() {
+    let server = MockServer::start(|_| MockResponse::status(200).delay(Duration::from_secs(3)));
+    let doi = server
+        .builder("10.1/slow")
+        .timeout(Duration::from_millis(200))
+        .deadline(Duration::from_secs(10))
+        .build();
+    let start = Instant::now();
+    let error = doi.resolve().unwrap_err();
+    // The configured timeout still applies, rather than the time remaining before the deadline.
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(matches!(error, DoiError::Timeout(_)));
+}
+
+#[test]
+fn verify_landing_dead_link() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/dead" => MockResponse::redirect(302, "/gone"),
+        "/10.1/head" => MockResponse::redirect(302, "/no-head"),
+        "/no-head" if request.method == "HEAD" => MockResponse::status(405),
+        "/no-head" => MockResponse::status(200),
+        _ => MockResponse::status(404),
+    });
+    assert_eq!(server.doi("10.1/dead").verify_landing().unwrap(), 404);
+    assert_eq!(server.doi("10.1/head").verify_landing().unwrap(), 200);
+    assert!(server.doi("10.1/unknown").verify_landing().is_err());
+}
+
+#[test]
+fn exists_many_preserves_order() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("missing") {
+            MockResponse::status(404)
+        } else if request.path.contains("dead") {
+            MockResponse::redirect(302, "/landing/gone")
+        } else if request.path.contains("gone") {
+            MockResponse::status(410)
+        } else if request.path.contains("broken") {
+            MockResponse::status(500)
+        } else {
+            MockResponse::status(200).delay(Duration::from_millis(50))
+        }
+    });
+    let dois: Vec<_> = [
+        "10.1/a",
+        "10.1/missing-1",
+        "10.1/b",
+        "10.1/dead",
+        "10.1/missing-2",
+        "10.1/broken",
+        "10.1/c",
+    ]
+    .iter()
+    .map(|doi| server.doi(doi))
+    .collect();
+    let results = Doi::exists_many(&dois, 3);
+    let exists: Vec<_> = results.iter().map(|r| r.as_ref().ok().copied()).collect();
+    assert_eq!(
+        exists,
+        [
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(true)
+        ]
+    );
+    let error = results[5].as_ref().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Status(500)));
+    assert_eq!(server.requests().len(), 8);
+    assert!(Doi::exists_many(&[], 4).is_empty());
+    assert!(Doi::exists_many(&[Doi::default()], 0)[0].is_err());
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn resolve_all_multiple_urls() {
+    let server = MockServer::start(|request| {
+        if request.path == "/api/handles/10.1/multi" {
+            MockResponse::json(
+                r#"{"responseCode": 1, "handle": "10.1/multi", "values": [
+                    {"index": 1, "type": "URL", "data": {"format": "string", "value": "https://example.org/a"}},
+                    {"index": 100, "type": "HS_ADMIN", "data": {"format": "admin", "value": {"index": 200}}},
+                    {"index": 2, "type": "URL", "data": {"format": "string", "value": "https://mirror.example.org/a"}}
+                ]}"#,
+            )
+        } else {
+            MockResponse::status(404)
+                .header("Content-Type", "application/json")
+                .body(r#"{"responseCode": 100, "handle": "10.1/missing"}"#)
+        }
+    });
+    let urls = server.doi("10.1/multi").resolve_all().unwrap();
+    assert_eq!(
+        urls,
+        ["https://example.org/a", "https://mirror.example.org/a"]
+    );
+    let error = server.doi("10.1/missing").resolve_all().unwrap_err();
+    assert!(matches!(error, DoiError::NotFound));
+}
+
+#[test]
+fn treat_forbidden_as_resolved() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/paywalled" => MockResponse::redirect(302, "/landing/paywall"),
+        "/landing/paywall" => MockResponse::status(403),
+        _ => MockResponse::status(403),
+    });
+    let error = server.doi("10.1/paywalled").resolve().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Status(403)));
+    assert!(server.doi("10.1/paywalled").exists().unwrap());
+
+    for hsts in [false, true] {
+        let doi = server
+            .builder("10.1/paywalled")
+            .treat_forbidden_as_resolved(true)
+            .hsts_upgrade(hsts)
+            .build();
+        assert_eq!(
+            doi.resolve().unwrap(),
+            format!("{}/landing/paywall", server.url())
+        );
+        assert!(doi.exists().unwrap());
+        // A 403 of the resolver itself is still an error.
+        let forbidden = server
+            .builder("10.1/forbidden")
+            .treat_forbidden_as_resolved(true)
+            .hsts_upgrade(hsts)
+            .build();
+        assert!(forbidden.resolve().is_err());
+    }
+}
+
+#[test]
+fn accept_statuses() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/quirky" => MockResponse::redirect(302, "/landing/quirky"),
+        _ => MockResponse::status(420),
+    });
+    let error = server.doi("10.1/quirky").resolve().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Status(420)));
+
+    let doi = server
+        .builder("10.1/quirky")
+        .accept_statuses(vec![418, 420])
+        .build();
+    assert_eq!(
+        doi.resolve().unwrap(),
+        format!("{}/landing/quirky", server.url())
+    );
+}
+
+#[test]
+fn default_builder_accepts_418() {
+    let server = MockServer::start(|_| MockResponse::status(418));
+    let doi = DoiBuilder::default()
+        .doi("10.1/teapot")
+        .env_proxy(false)
+        .resolver(Resolver::BaseUrl(server.url()))
+        .build();
+    assert_eq!(
+        doi.resolve().unwrap(),
+        format!("{}/10.1/teapot", server.url())
+    );
+}
+
+#[test]
+fn landing_info() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10.1/pdf" => MockResponse::redirect(302, "/files/paper.pdf"),
+        "/files/paper.pdf" => MockResponse::status(200)
+            .header("Content-Type", "application/pdf")
+            .body(vec![0; 1234]),
+        "/10.1/bare" => MockResponse::redirect(302, "/landing/bare"),
+        _ => MockResponse::status(200),
+    });
+    let (length, content_type) = server.doi("10.1/pdf").landing_info().unwrap();
+    assert_eq!(length, Some(1234));
+    assert_eq!(content_type.as_deref(), Some("application/pdf"));
+    assert_eq!(
+        server.requests().last().unwrap().method,
+        "HEAD",
+        "the landing resource is not downloaded"
+    );
+
+    let (_, content_type) = server.doi("10.1/bare").landing_info().unwrap();
+    assert_eq!(content_type, None);
+}
+
+#[test]
+fn user_agent_header() {
+    let server = MockServer::start(|_| MockResponse::json("{}"));
+    server.doi("10.1/default").resolve().unwrap();
+    let doi = server
+        .builder("10.1/custom")
+        .user_agent("my-app/1.0 (mailto:me@example.com)")
+        .build();
+    doi.resolve().unwrap();
+    #[cfg(feature = "metadata")]
+    doi.metadata().unwrap();
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].header("User-Agent"),
+        Some(doi::DEFAULT_USER_AGENT)
+    );
+    assert!(doi::DEFAULT_USER_AGENT.starts_with("doi-rs/"));
+    for request in &requests[1..] {
+        assert_eq!(
+            request.header("User-Agent"),
+            Some("my-app/1.0 (mailto:me@example.com)")
+        );
+    }
+}
+
+/// Returns a handler failing with `status` for the first `failures` requests.
+fn failing(failures: usize, status: u16) -> impl Fn(&MockRequest) -> MockResponse {
+    let count = AtomicUsize::new(0);
+    move |_| {
+        if count.fetch_add(1, Ordering::SeqCst) < failures {
+            MockResponse::status(status)
+        } else {
+            MockResponse::json("{}")
+        }
+    }
+}
+
+#[test]
+fn retries_transient_failures() {
+    let server = MockServer::start(failing(2, 503));
+    let doi = server
+        .builder("10.1/flaky")
+        .retries(2)
+        .retry_backoff(Duration::from_millis(20))
+        .build();
+    let start = Instant::now();
+    assert!(doi.resolve().is_ok());
+    // The backoff doubles: 20 ms before the first retry and 40 ms before the second.
+    assert!(start.elapsed() >= Duration::from_millis(60));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn retries_exhausted() {
+    let server = MockServer::start(failing(3, 429));
+    let doi = server
+        .builder("10.1/throttled")
+        .retries(1)
+        .retry_backoff(Duration::from_millis(10))
+        .build();
+    let error = doi.resolve().unwrap_err();
+    assert_eq!(error.kind(), Some(ResolveErrorKind::Status(429)));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn retries_not_on_not_found() {
+    let server = MockServer::start(failing(1, 404));
+    let doi = server.builder("10.1/unknown").retries(3).build();
+    assert!(matches!(doi.resolve(), Err(DoiError::NotFound)));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn retries_metadata() {
+    let server = MockServer::start(failing(1, 500));
+    let doi = server
+        .builder("10.1/flaky")
+        .retries(1)
+        .retry_backoff(Duration::from_millis(10))
+        .build();
+    assert!(doi.metadata_json().is_ok());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn resolve_many_shared_agent() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("missing") {
+            MockResponse::status(404)
+        } else if request.path.starts_with("/landing") {
+            MockResponse::status(200)
+        } else {
+            MockResponse::redirect(302, &format!("/landing{}", request.path))
+        }
+    });
+    let dois: Vec<_> = ["10.1/a", "10.1/missing", "10.1/b"]
+        .iter()
+        .map(|doi| server.doi(doi))
+        .chain([Doi::default()])
+        .collect();
+    let agent = ureq::AgentBuilder::new().user_agent("shared-agent").build();
+    let expected = [
+        Some(format!("{}/landing/10.1/a", server.url())),
+        None,
+        Some(format!("{}/landing/10.1/b", server.url())),
+        None,
+    ];
+    for results in [
+        Doi::resolve_many(&dois, &agent),
+        Doi::resolve_many_parallel(&dois, &agent, 2),
+    ] {
+        let links: Vec<_> = results.iter().map(|r| r.as_ref().ok().cloned()).collect();
+        assert_eq!(links, expected);
+        assert!(matches!(results[1], Err(DoiError::NotFound)));
+        assert!(matches!(results[3], Err(DoiError::NotSet)));
+    }
+    assert!(server
+        .requests()
+        .iter()
+        .all(|request| request.header("User-Agent") == Some("shared-agent")));
+}
+
+#[test]
+fn clone_agent_from_shares_agent() {
+    let server = MockServer::start(|_| MockResponse::status(200));
+    let configured = server
+        .builder("10.1/first")
+        .user_agent("configured-agent")
+        .timeout(Duration::from_secs(5))
+        .build();
+    let other = server
+        .builder("10.1/second")
+        .user_agent("ignored-agent")
+        .clone_agent_from(&configured)
+        .build();
+    assert_eq!(
+        configured.resolve().unwrap(),
+        format!("{}/10.1/first", server.url())
+    );
+    assert_eq!(
+        other.resolve().unwrap(),
+        format!("{}/10.1/second", server.url())
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests
+        .iter()
+        .all(|request| request.header("User-Agent") == Some("configured-agent")));
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn alias_target_hs_alias() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/api/handles/10.1/alias" => MockResponse::json(
+            r#"{"responseCode": 1, "handle": "10.1/alias", "values": [
+                {"index": 1, "type": "HS_ALIAS", "data": {"format": "string", "value": "10.1/canonical"}},
+                {"index": 100, "type": "HS_ADMIN", "data": {"format": "admin", "value": {"index": 200}}}
+            ]}"#,
+        ),
+        "/api/handles/10.1/canonical" => MockResponse::json(
+            r#"{"responseCode": 1, "handle": "10.1/canonical", "values": [
+                {"index": 1, "type": "URL", "data": {"format": "string", "value": "https://example.org/a"}}
+            ]}"#,
+        ),
+        _ => MockResponse::status(404)
+            .header("Content-Type", "application/json")
+            .body(r#"{"responseCode": 100}"#),
+    });
+    let target = server.doi("10.1/alias").alias_target().unwrap().unwrap();
+    assert_eq!(target.doi.as_deref(), Some("10.1/canonical"));
+    // The target shares the configuration (here, the resolver).
+    assert_eq!(target.alias_target().unwrap(), None);
+    assert!(matches!(
+        server.doi("10.1/missing").alias_target(),
+        Err(DoiError::NotFound)
+    ));
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+use doi::Doi;
+
+#[test]
+fn serde_round_trip() {
+    let doi = Doi::new("10.1109/TCSII.2024.3366282");
+    let json = serde_json::to_string(&doi).unwrap();
+    assert_eq!(json, r#""10.1109/TCSII.2024.3366282""#);
+    let parsed: Doi = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.doi, doi.doi);
+
+    let dois = vec![Doi::new("10.1145/3643832.3661865"), Doi::default()];
+    let json = serde_json::to_string(&dois).unwrap();
+    assert_eq!(json, r#"["10.1145/3643832.3661865",null]"#);
+    let parsed: Vec<Doi> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, dois);
+}
+
+#[test]
+fn serde_deserialize_prefixed_forms() {
+    for input in [
+        r#""doi:10.1109/TCSII.2024.3366282""#,
+        r#""https://doi.org/10.1109/TCSII.2024.3366282""#,
+        r#"" http://dx.doi.org/10.1109/TCSII.2024.3366282 ""#,
+        r#""https://www.doi.org/10.1109%2FTCSII.2024.3366282?from=feed#top""#,
+    ] {
+        let doi: Doi = serde_json::from_str(input).unwrap();
+        assert_eq!(
+            doi.doi.as_deref(),
+            Some("10.1109/TCSII.2024.3366282"),
+            "{}",
+            input
+        );
+    }
+    let error = serde_json::from_str::<Doi>(r#""not a doi""#).unwrap_err();
+    assert!(error.to_string().contains("Invalid DOI"), "{}", error);
+    assert!(serde_json::from_str::<Doi>("42").is_err());
+}
+
+#[test]
+fn serde_deserialize_null() {
+    let doi: Doi = serde_json::from_str("null").unwrap();
+    assert_eq!(doi, Doi::default());
+    assert!(!doi.is_set());
+    let doi: Option<Doi> = serde_json::from_str("null").unwrap();
+    assert!(doi.is_none());
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn serde_metadata_type_csl_strings() {
+    use doi::DoiMetadataType;
+    let json = serde_json::to_string(&DoiMetadataType::ArticleJournal).unwrap();
+    assert_eq!(json, r#""article-journal""#);
+    let parsed: DoiMetadataType = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, DoiMetadataType::ArticleJournal);
+
+    let misc = DoiMetadataType::MISC("grant".to_string());
+    let json = serde_json::to_string(&misc).unwrap();
+    assert_eq!(json, r#""grant""#);
+    let parsed: DoiMetadataType = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, misc);
+    let misc = DoiMetadataType::MISC("article-journal".to_string());
+    let parsed: DoiMetadataType =
+        serde_json::from_str(&serde_json::to_string(&misc).unwrap()).unwrap();
+    assert_eq!(parsed, DoiMetadataType::ArticleJournal);
+    assert!(serde_json::from_str::<DoiMetadataType>("42").is_err());
+}
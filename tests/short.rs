@@ -0,0 +1,52 @@
+#![cfg(feature = "metadata")]
+
+mod common;
+
+use common::{MockResponse, MockServer};
+use doi::{Doi, DoiError};
+
+#[test]
+fn shortdoi_expand_and_shorten() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/10/b6zq?format=json" | "/10.1109/TCSII.2024.3366282?format=json" => MockResponse::json(
+            r#"{"DOI": "10.1109/TCSII.2024.3366282", "ShortDOI": "10/b6zq", "IsNew": false}"#,
+        ),
+        _ => MockResponse::status(400),
+    });
+    let builder = |doi: &str| {
+        let mut builder = server.builder(doi);
+        builder.shortdoi_service(server.url());
+        builder
+    };
+    let full = builder("10/b6zq").build().resolve_short().unwrap();
+    assert_eq!(full.doi.as_deref(), Some("10.1109/TCSII.2024.3366282"));
+    let short = builder("10.1109/TCSII.2024.3366282")
+        .build()
+        .to_short()
+        .unwrap();
+    assert_eq!(short.doi.as_deref(), Some("10/b6zq"));
+    assert!(short.is_short());
+    assert_eq!(server.requests().len(), 2);
+    // A shortDOI is already short.
+    let short = builder("10/b6zq").build().to_short().unwrap();
+    assert_eq!(short.doi.as_deref(), Some("10/b6zq"));
+    assert_eq!(server.requests().len(), 2);
+    // An unknown shortDOI is rejected by the service.
+    assert!(builder("10/zzzz").build().resolve_short().is_err());
+}
+
+#[test]
+fn shortdoi_rejects_full_doi() {
+    let server = MockServer::start(|_| MockResponse::json("{}"));
+    let mut builder = server.builder("10.1109/TCSII.2024.3366282");
+    builder.shortdoi_service(server.url());
+    for doi in ["10.1109/TCSII.2024.3366282", "10/", "10/a-b"] {
+        let error = builder.doi(doi).build().resolve_short().unwrap_err();
+        assert!(matches!(error, DoiError::Parse(_)), "{}: {:?}", doi, error);
+    }
+    assert!(matches!(
+        Doi::default().resolve_short(),
+        Err(DoiError::NotSet)
+    ));
+    assert!(server.requests().is_empty());
+}